@@ -1,20 +1,18 @@
-use clap::Clap;
+use clap::{Arg, Command};
 use scryfall::card::Game;
 use scryfall::search::prelude::*;
 
-#[derive(Clap)]
-struct Opts {
-    card_name: String,
-}
-
 fn main() -> scryfall::Result<()> {
-    let opts: Opts = Opts::parse();
+    let matches = Command::new("top-printings")
+        .arg(Arg::new("card_name").required(true))
+        .get_matches();
+    let card_name = matches.value_of("card_name").unwrap();
 
     let mut search_options = SearchOptions::new();
     search_options
         .unique(UniqueStrategy::Prints)
         .sort(SortOrder::Usd, SortDirection::Descending)
-        .query(exact(opts.card_name).and(in_game(Game::Paper)));
+        .query(exact(card_name).and(in_game(Game::Paper)));
 
     println!("{}", serde_urlencoded::to_string(&search_options).unwrap());
 
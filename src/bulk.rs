@@ -15,6 +15,7 @@
 //!
 //! See also: [Official Docs](https://scryfall.com/docs/api/bulk-data)
 
+use std::fmt;
 use std::fs::File;
 use std::io;
 use std::io::BufReader;
@@ -23,14 +24,15 @@ use std::path::Path;
 use cfg_if::cfg_if;
 use chrono::{DateTime, Utc};
 use serde::de::DeserializeOwned;
-use serde::Deserialize;
-use uuid::Uuid;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::card::Card;
+use crate::list::List;
 use crate::ruling::Ruling;
 use crate::uri::Uri;
 use crate::util::array_stream_reader::ArrayStreamReader;
-use crate::util::BULK_DATA_URL;
+use crate::util::{Uuid, BULK_DATA_URL};
 
 /// Scryfall provides daily exports of our card data in bulk files. Each of
 /// these files is represented as a bulk_data object via the API. URLs for files
@@ -79,6 +81,12 @@ pub struct BulkDataFile<T> {
     pub updated_at: DateTime<Utc>,
 
     /// The size of this file in integer bytes.
+    #[serde(default)]
+    pub size: usize,
+
+    /// The compressed size of this file in integer bytes. Scryfall no longer
+    /// reports this, in which case it is 0.
+    #[serde(default)]
     pub compressed_size: usize,
 
     /// The MIME type of this file.
@@ -117,9 +125,10 @@ impl<T: DeserializeOwned> BulkDataFile<T> {
         }
     }
 
-    /// Gets a BulkDataFile of the specified type.
-    pub fn of_type(bulk_type: &str) -> crate::Result<Self> {
-        Uri::from(BULK_DATA_URL.join(bulk_type)?).fetch()
+    /// Gets a BulkDataFile of the specified type. The type can be either a
+    /// [`BulkDataType`] or its string representation.
+    pub fn of_type(bulk_type: impl fmt::Display) -> crate::Result<Self> {
+        Uri::from(BULK_DATA_URL.join(&bulk_type.to_string())?).fetch()
     }
 
     /// Gets a BulkDataFile with the specified unique ID.
@@ -156,23 +165,80 @@ impl<T: DeserializeOwned> BulkDataFile<T> {
     }
 }
 
+impl BulkDataFile<Value> {
+    /// Returns all the bulk data files currently available on Scryfall.
+    ///
+    /// The files hold different kinds of objects, so their contents are only
+    /// available as raw JSON values. To download a file as cards or rulings,
+    /// use [`of_type`][BulkDataFile::of_type] or [`id`][BulkDataFile::id]
+    /// with the appropriate type parameter.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::bulk::{BulkDataFile, BulkDataType};
+    /// let files = BulkDataFile::all().unwrap();
+    /// assert!(
+    ///     files
+    ///         .iter()
+    ///         .any(|f| f.bulk_type == BulkDataType::OracleCards.to_string())
+    /// );
+    /// ```
+    pub fn all() -> crate::Result<Vec<Self>> {
+        Uri::<List<Self>>::from(BULK_DATA_URL.clone()).fetch_all()
+    }
+}
+
+/// The kinds of bulk data files provided by Scryfall.
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum BulkDataType {
+    /// One card object for each Oracle ID on Scryfall.
+    OracleCards,
+    /// Card objects that together contain all unique artworks.
+    UniqueArtwork,
+    /// Every card object on Scryfall in English or the printed language if the
+    /// card is only available in one language.
+    DefaultCards,
+    /// Every card object on Scryfall in every language.
+    AllCards,
+    /// All rulings on Scryfall.
+    Rulings,
+}
+
+impl fmt::Display for BulkDataType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                BulkDataType::OracleCards => "oracle_cards",
+                BulkDataType::UniqueArtwork => "unique_artwork",
+                BulkDataType::DefaultCards => "default_cards",
+                BulkDataType::AllCards => "all_cards",
+                BulkDataType::Rulings => "rulings",
+            }
+        )
+    }
+}
+
 /// An iterator containing one Scryfall card object for each Oracle ID on
 /// Scryfall. The chosen sets for the cards are an attempt to return the most
 /// up-to-date recognizable version of the card.
 pub fn oracle_cards() -> crate::Result<impl Iterator<Item = crate::Result<Card>>> {
-    BulkDataFile::of_type("oracle_cards")?.load_iter()
+    BulkDataFile::of_type(BulkDataType::OracleCards)?.load_iter()
 }
 
 /// An iterator of Scryfall card objects that together contain all unique
 /// artworks. The chosen cards promote the best image scans.
 pub fn unique_artwork() -> crate::Result<impl Iterator<Item = crate::Result<Card>>> {
-    BulkDataFile::of_type("unique_artwork")?.load_iter()
+    BulkDataFile::of_type(BulkDataType::UniqueArtwork)?.load_iter()
 }
 
 /// An iterator containing every card object on Scryfall in English or the
 /// printed language if the card is only available in one language.
 pub fn default_cards() -> crate::Result<impl Iterator<Item = crate::Result<Card>>> {
-    BulkDataFile::of_type("default_cards")?.load_iter()
+    BulkDataFile::of_type(BulkDataType::DefaultCards)?.load_iter()
 }
 
 /// An iterator of every card object on Scryfall in every language.
@@ -180,13 +246,13 @@ pub fn default_cards() -> crate::Result<impl Iterator<Item = crate::Result<Card>
 /// # Note
 /// This currently takes about 2GB of RAM before returning 👀.
 pub fn all_cards() -> crate::Result<impl Iterator<Item = crate::Result<Card>>> {
-    BulkDataFile::of_type("all_cards")?.load_iter()
+    BulkDataFile::of_type(BulkDataType::AllCards)?.load_iter()
 }
 
 /// An iterator of all Rulings on Scryfall. Each ruling refers to cards via an
 /// `oracle_id`.
 pub fn rulings() -> crate::Result<impl Iterator<Item = crate::Result<Ruling>>> {
-    BulkDataFile::of_type("rulings")?.load_iter()
+    BulkDataFile::of_type(BulkDataType::Rulings)?.load_iter()
 }
 
 #[cfg(test)]
@@ -231,6 +297,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_bulk_data_file() {
+        use super::{BulkDataFile, BulkDataType};
+        use crate::card::Card;
+
+        let file: BulkDataFile<Card> = serde_json::from_str(
+            r#"{
+              "object": "bulk_data",
+              "id": "27bf3214-1271-490b-bdfe-c0be6c23d02e",
+              "type": "oracle_cards",
+              "updated_at": "2021-04-27T09:02:35.483+00:00",
+              "uri": "https://api.scryfall.com/bulk-data/27bf3214-1271-490b-bdfe-c0be6c23d02e",
+              "name": "Oracle Cards",
+              "description": "A JSON file containing one Scryfall card object for each Oracle ID on Scryfall.",
+              "size": 57403420,
+              "download_uri": "https://c2.scryfall.com/file/scryfall-bulk/oracle-cards/oracle-cards-20210427090235.json",
+              "content_type": "application/json",
+              "content_encoding": "gzip"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(file.bulk_type, BulkDataType::OracleCards.to_string());
+        assert_eq!(file.size, 57403420);
+        assert_eq!(file.compressed_size, 0);
+    }
+
     #[test]
     fn test_parse_list() {
         use serde_json::Deserializer;
@@ -5,43 +5,74 @@
 //! documented in the official [scryfall page](https://scryfall.com/docs/api/cards).
 mod border_color;
 mod card_faces;
+mod card_identifier;
+mod card_type;
 mod color;
+mod finish;
 mod frame;
 mod frame_effect;
 mod game;
+mod image_version;
+mod language;
 mod layout;
 mod legality;
+mod mana_cost;
+mod oracle_symbol;
+mod pow_tou;
 mod preview;
 mod price;
 mod rarity;
 mod related_card;
+mod type_line;
+mod watermark;
 
 use std::collections::hash_map::HashMap;
+use std::convert::TryFrom;
+use std::io::Read;
 
-use chrono::NaiveDate;
+use chrono::{Datelike, NaiveDate};
+#[cfg(feature = "async")]
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
 use url::Url;
-use uuid::Uuid;
 
 pub use self::border_color::BorderColor;
 pub use self::card_faces::CardFace;
+pub use self::card_identifier::CardIdentifier;
+pub use self::card_type::CardType;
 pub use self::color::{Color, Colors, Multicolored};
+pub use self::finish::Finish;
 pub use self::frame::Frame;
 pub use self::frame_effect::FrameEffect;
 pub use self::game::Game;
+pub use self::image_version::ImageVersion;
+pub use self::language::Language;
 pub use self::layout::Layout;
 pub use self::legality::Legality;
+pub use self::mana_cost::{ManaCost, ManaSymbol};
+pub use self::oracle_symbol::OracleSymbol;
+pub use self::pow_tou::PowTou;
 pub use self::preview::Preview;
 pub use self::price::Price;
 pub use self::rarity::Rarity;
-pub use self::related_card::RelatedCard;
+pub use self::related_card::{Component, RelatedCard};
+pub use self::type_line::TypeLine;
+pub use self::watermark::Watermark;
+use crate::catalog::Catalog;
+use crate::client::ScryfallClient;
+use crate::deck;
+use crate::error::Error;
 use crate::format::Format;
-use crate::list::{List, ListIter};
+use crate::list::{List, ListIter, UniqueCards};
 use crate::ruling::Ruling;
+use crate::search::advanced::SortOrder;
+use crate::search::param::value::Currency;
 use crate::search::Search;
 use crate::set::{Set, SetCode, SetType};
 use crate::uri::Uri;
-use crate::util::CARDS_URL;
+#[cfg(feature = "async")]
+use crate::uri::{page_stream, NextPage};
+use crate::util::{Uuid, CARDS_URL};
 
 /// Card objects represent individual Magic: The Gathering cards that players
 /// could obtain and add to their collection (with a few minor exceptions).
@@ -77,8 +108,8 @@ pub struct Card {
     /// A unique ID for this card in Scryfall’s database.
     pub id: Uuid,
 
-    // TODO(msmorgan): Language enum? https://scryfall.com/docs/api/languages
-    /// A language code for this printing.
+    /// A language code for this printing. See [`Card::language`] for a parsed
+    /// value.
     pub lang: String,
 
     /// This card’s Magic Online ID (also known as the Catalog ID), if any. A
@@ -112,7 +143,7 @@ pub struct Card {
     pub prints_search_uri: Uri<List<Card>>,
 
     /// A link to this card’s rulings list on Scryfall’s API.
-    pub rulings_uri: Uri<Vec<Ruling>>,
+    pub rulings_uri: Uri<List<Ruling>>,
 
     /// A link to this card’s permapage on Scryfall’s website.
     pub scryfall_uri: Url,
@@ -158,6 +189,7 @@ pub struct Card {
 
     /// An array of keywords that this card uses, such as 'Flying' and
     /// 'Cumulative upkeep'.
+    #[serde(default)]
     pub keywords: Vec<String>,
 
     /// A code for this card’s layout.
@@ -239,6 +271,11 @@ pub struct Card {
     /// True if this card was only released in a video game.
     pub digital: bool,
 
+    /// The finishes this printing is available in. Older data may not have
+    /// this, in which case it is empty. See [`has_finish`][Card::has_finish].
+    #[serde(default)]
+    pub finishes: Vec<Finish>,
+
     /// The just-for-fun name printed on the card (such as for Godzilla series
     /// cards).
     pub flavor_name: Option<String>,
@@ -346,10 +383,50 @@ pub struct Card {
     pub preview: Preview,
     /* ======================
      * endregion Print Fields */
+    /// Any fields sent by Scryfall that this struct doesn't have, such as
+    /// `object`. These are kept so that no data is lost when the card is
+    /// serialized again. See [`Card::from_json_strict`] to reject them
+    /// instead.
+    #[serde(flatten)]
+    pub extra_fields: HashMap<String, serde_json::Value>,
 }
 
 impl Card {
-    /// Fetches a random card.
+    /// Deserializes a card from JSON, failing if it has any fields this
+    /// struct doesn't know about, other than `object`. This can be used to
+    /// detect when Scryfall adds new fields to its cards.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::Card;
+    /// let card = Card::named("Lightning Bolt").unwrap();
+    /// let json = serde_json::to_string(&card).unwrap();
+    /// assert_eq!(Card::from_json_strict(&json).unwrap(), card);
+    ///
+    /// let mut value = serde_json::to_value(&card).unwrap();
+    /// value["new_field"] = serde_json::json!(true);
+    /// assert!(Card::from_json_strict(&value.to_string()).is_err());
+    /// ```
+    pub fn from_json_strict(json: &str) -> crate::Result<Card> {
+        let card: Card = serde_json::from_str(json)?;
+        let mut unknown = card
+            .extra_fields
+            .keys()
+            .filter(|field| field.as_str() != "object")
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        if unknown.is_empty() {
+            return Ok(card);
+        }
+        unknown.sort_unstable();
+        Err(Error::Other(format!(
+            "Unknown card fields: {}",
+            unknown.join(", ")
+        )))
+    }
+
+    /// Fetches a random card. To pick a random card matching a query, use
+    /// [`Card::random_with`].
     ///
     /// # Examples
     /// ```rust
@@ -361,11 +438,15 @@ impl Card {
     /// # }
     /// ```
     pub fn random() -> crate::Result<Card> {
-        Uri::from(CARDS_URL.join("random/")?).fetch()
+        ScryfallClient::with_default(|client| client.random())
     }
 
     /// Returns a [`ListIter`] of the cards that match the search terms.
     ///
+    /// The first page is requested before this returns, so a malformed query
+    /// or a network error is reported here rather than by the iterator. Only
+    /// the requests for later pages can fail during iteration.
+    ///
     /// # Examples
     /// ```rust
     /// use scryfall::card::Card;
@@ -400,9 +481,43 @@ impl Card {
     /// # }
     /// ```
     pub fn search(query: impl Search) -> crate::Result<ListIter<Card>> {
-        let mut url = CARDS_URL.join("search/")?;
-        query.write_query(&mut url)?;
-        Uri::from(url).fetch_iter()
+        ScryfallClient::with_default(|client| client.search(query))
+    }
+
+    /// Returns a [`ListIter`] of the cards that match the search terms,
+    /// reporting any error with the first page before returning.
+    ///
+    /// This is the same as [`Card::search`], which already requests the first
+    /// page eagerly. It is provided for code that wants to make that
+    /// guarantee explicit at the call site.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use scryfall::search::prelude::*;
+    /// use scryfall::{Card, Error};
+    /// match Card::search_checked(power(gte(NumProperty::Power))) {
+    ///     Ok(_) => panic!("The query should have been rejected"),
+    ///     Err(Error::ScryfallError(e)) => assert!(e.warnings.len() > 0),
+    ///     Err(e) => panic!("Wrong error type: {0} {0:?}", e),
+    /// }
+    /// ```
+    pub fn search_checked(query: impl Search) -> crate::Result<ListIter<Card>> {
+        Card::search(query)
+    }
+
+    /// Returns the first page of cards that match the search terms, along with
+    /// the total number of cards found and a link to the next page, if there
+    /// is one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// let page = Card::search_page("t:goblin").unwrap();
+    /// assert!(page.has_more);
+    /// assert!(page.total_cards.unwrap() > page.data.len());
+    /// ```
+    pub fn search_page(query: impl Search) -> crate::Result<List<Card>> {
+        ScryfallClient::with_default(|client| client.search_page(query))
     }
 
     /// Returns all cards that match a query, as a `Vec`. If there is more than
@@ -419,13 +534,33 @@ impl Card {
     /// # }
     /// ```
     pub fn search_all(query: impl Search) -> crate::Result<Vec<Card>> {
-        let mut url = CARDS_URL.join("search/")?;
-        query.write_query(&mut url)?;
-        Uri::from(url).fetch_all()
+        ScryfallClient::with_default(|client| client.search_all(query))
     }
 
     /// Fetches a random card matching a search query.
     ///
+    /// Only the query itself is sent, since the random endpoint ignores
+    /// sorting, unique rollup, and the other options of
+    /// [`SearchOptions`][crate::search::advanced::SearchOptions].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use scryfall::Card;
+    /// # fn main() -> scryfall::Result<()> {
+    /// use scryfall::search::prelude::*;
+    /// let card = Card::random_with(type_line("goblin").and(cmc(lte(2))))?;
+    /// assert!(card.type_line.contains("Goblin"));
+    /// assert!(card.cmc <= 2.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn random_with(query: impl Search) -> crate::Result<Card> {
+        ScryfallClient::with_default(|client| client.random_with(query))
+    }
+
+    /// Fetches a random card matching a search query. This is the same as
+    /// [`Card::random_with`].
+    ///
     /// # Examples
     /// ```rust
     /// # use scryfall::Card;
@@ -436,9 +571,7 @@ impl Card {
     /// # }
     /// ```
     pub fn search_random(query: impl Search) -> crate::Result<Card> {
-        let mut url = CARDS_URL.join("random/")?;
-        query.write_query(&mut url)?;
-        Uri::from(url).fetch()
+        ScryfallClient::with_default(|client| client.search_random(query))
     }
 
     /// Return a card with the exact name.
@@ -458,9 +591,7 @@ impl Card {
     /// assert!(Card::named("Name that doesn't exist").is_err())
     /// ```
     pub fn named(name: &str) -> crate::Result<Card> {
-        let mut url = CARDS_URL.join("named")?;
-        url.query_pairs_mut().append_pair("exact", name);
-        Uri::from(url).fetch()
+        ScryfallClient::with_default(|client| client.named(name))
     }
 
     /// Return a card using the scryfall fuzzy finder.
@@ -474,9 +605,52 @@ impl Card {
     /// }
     /// ```
     pub fn named_fuzzy(query: &str) -> crate::Result<Card> {
-        let mut url = CARDS_URL.join("named")?;
-        url.query_pairs_mut().append_pair("fuzzy", query);
-        Uri::from(url).fetch()
+        ScryfallClient::with_default(|client| client.named_fuzzy(query))
+    }
+
+    /// Returns the names of the cards `query` could refer to with the scryfall
+    /// fuzzy finder. If the fuzzy finder finds one card, that is the only name
+    /// returned. If `query` is [ambiguous][crate::Error::is_ambiguous], this
+    /// falls back to [`autocomplete`][Card::autocomplete], since Scryfall
+    /// doesn't report the cards that matched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// assert_eq!(
+    ///     Card::named_fuzzy_candidates("Light Bolt").unwrap(),
+    ///     ["Lightning Bolt"]
+    /// );
+    /// let names = Card::named_fuzzy_candidates("jace").unwrap();
+    /// assert!(names.len() > 1);
+    /// ```
+    pub fn named_fuzzy_candidates(query: &str) -> crate::Result<Vec<String>> {
+        match Card::named_fuzzy(query) {
+            Ok(card) => Ok(vec![card.name]),
+            Err(e) if e.is_ambiguous() => Card::autocomplete(query),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns up to 20 full English card names that could be autocompletions
+    /// of `query`. An empty or blank `query` returns no names, without
+    /// contacting Scryfall.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// let names = Card::autocomplete("thal").unwrap();
+    /// assert!(names.iter().any(|n| n == "Thalia, Guardian of Thraben"));
+    /// assert!(Card::autocomplete("  ").unwrap().is_empty());
+    /// ```
+    pub fn autocomplete(query: &str) -> crate::Result<Vec<String>> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut url = CARDS_URL.join("autocomplete")?;
+        url.query_pairs_mut().append_pair("q", query);
+        Ok(Uri::<Catalog>::from(url).fetch()?.data)
     }
 
     /// Fetch a card by its set and number.
@@ -540,6 +714,50 @@ impl Card {
         Uri::from(CARDS_URL.join("arena/")?.join(&arena_id.to_string())?).fetch()
     }
 
+    /// Fetches the card in a line of an MTG Arena deck export, such as
+    /// `1 Lightning Bolt (M10) 146`, along with the number of copies. If the
+    /// line has no set and collector number, the newest printing with that
+    /// name is returned.
+    ///
+    /// Lines are read like those of
+    /// [`Deck::from_text`][crate::deck::Deck::from_text], so counts like `4x`
+    /// and a `SB:` prefix are accepted. Section headers
+    /// like `Deck` and `Sideboard` aren't card lines and return an error. Arena uses its own code for a few sets, so those
+    /// printings may not be found.
+    ///
+    /// To only search for cards that are on Arena, use
+    /// [`game(Game::Arena)`][crate::search::param::value::game].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// let (count, card) = Card::from_arena_export("4 Lightning Bolt (M10) 146").unwrap();
+    /// assert_eq!(count, 4);
+    /// assert_eq!(card.name, "Lightning Bolt");
+    /// assert_eq!(card.collector_number, "146");
+    /// assert!(Card::from_arena_export("Sideboard").is_err());
+    /// ```
+    pub fn from_arena_export(line: &str) -> crate::Result<(usize, Card)> {
+        let (count, identifier) = match deck::parse_line(line) {
+            deck::Line::Card {
+                quantity,
+                identifier,
+                ..
+            } => (quantity, identifier),
+            _ => {
+                return Err(Error::Other(format!(
+                    "Invalid Arena export line: {:?}",
+                    line
+                )))
+            },
+        };
+        let card = Card::collection(std::slice::from_ref(&identifier))?
+            .pop()
+            .flatten()
+            .ok_or_else(|| Error::Other(format!("Card not found: {:?}", line)))?;
+        Ok((count, card))
+    }
+
     /// Fetch a card by its tcgplayer id.
     ///
     /// # Examples
@@ -559,7 +777,8 @@ impl Card {
         .fetch()
     }
 
-    /// Fetch a card by its Uuid.
+    /// Fetch a card by its Scryfall id. A [`Uuid`] can be parsed from a string,
+    /// which fails if the string isn't a valid id.
     ///
     /// # Examples
     /// ```rust
@@ -569,7 +788,1271 @@ impl Card {
     ///     Err(e) => panic!("{:?}", e),
     /// }
     /// ```
+    #[allow(clippy::self_named_constructors)]
     pub fn card(scryfall_id: Uuid) -> crate::Result<Card> {
-        Uri::from(CARDS_URL.join(&scryfall_id.to_string())?).fetch()
+        ScryfallClient::with_default(|client| client.card(scryfall_id))
+    }
+
+    /// Fetch a card by its Oracle id, which is shared by all of its printings.
+    /// Scryfall picks which printing to return, which is usually the most
+    /// recent one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// let bolt = Card::named("Lightning Bolt").unwrap();
+    /// let card = Card::oracle(bolt.oracle_id).unwrap();
+    /// assert_eq!(card.name, "Lightning Bolt");
+    /// assert_eq!(card.oracle_id, bolt.oracle_id);
+    /// ```
+    pub fn oracle(oracle_id: Uuid) -> crate::Result<Card> {
+        ScryfallClient::with_default(|client| client.oracle(oracle_id))
+    }
+
+    /// Fetch many cards at once, one for each of the `identifiers`.
+    ///
+    /// The returned cards are in the same order as the identifiers. If no card
+    /// matches an identifier, its position holds `None` instead. Scryfall
+    /// matches names loosely, so a card's name may differ from the one in its
+    /// identifier, for example in accents.
+    ///
+    /// Scryfall accepts at most 75 identifiers per request, so larger slices
+    /// are split into several requests.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, CardIdentifier};
+    /// let cards = Card::collection(&[
+    ///     CardIdentifier::Name("Ancient Tomb".to_string()),
+    ///     CardIdentifier::Name("Not a card name".to_string()),
+    ///     CardIdentifier::CollectorNumberSet {
+    ///         collector_number: "150".to_string(),
+    ///         set: "mrd".to_string(),
+    ///     },
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(cards[0].as_ref().unwrap().name, "Ancient Tomb");
+    /// assert!(cards[1].is_none());
+    /// assert_eq!(cards[2].as_ref().unwrap().name, "Chalice of the Void");
+    /// ```
+    pub fn collection(identifiers: &[CardIdentifier]) -> crate::Result<Vec<Option<Card>>> {
+        let uri = Uri::<Collection>::from(CARDS_URL.join("collection")?);
+        let mut cards = Vec::with_capacity(identifiers.len());
+        for chunk in identifiers.chunks(COLLECTION_MAX_IDENTIFIERS) {
+            let collection = uri.post(&CollectionRequest { identifiers: chunk })?;
+            cards.extend(collate(chunk, collection.data, collection.not_found)?);
+        }
+        Ok(cards)
+    }
+
+    /// Parses this card's mana cost into its individual symbols.
+    ///
+    /// Returns `None` if this card has no `mana_cost`, which is the case for
+    /// multi-faced cards, where the cost is reported in each card face.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, Colors};
+    /// let card = Card::named("Sphinx of the Steel Wind").unwrap();
+    /// let cost = card.parsed_mana_cost().unwrap().unwrap();
+    /// assert_eq!(cost.cmc(), card.cmc);
+    /// assert_eq!(cost.colors(), Colors::ESPER);
+    /// ```
+    pub fn parsed_mana_cost(&self) -> Option<crate::Result<ManaCost>> {
+        self.mana_cost.as_deref().map(ManaCost::parse)
+    }
+
+    /// Parses this card's type line into its supertypes, card types and
+    /// subtypes. For multi-faced cards, the types of every face are combined;
+    /// see [`TypeLine::parse`].
+    ///
+    /// Returns `None` if this card has no type line.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// let card = Card::named("Delver of Secrets").unwrap();
+    /// let type_line = card.parsed_type_line().unwrap();
+    /// assert_eq!(type_line.card_types, ["Creature"]);
+    /// assert_eq!(type_line.subtypes, ["Human", "Wizard", "Insect"]);
+    /// ```
+    pub fn parsed_type_line(&self) -> Option<TypeLine> {
+        TypeLine::parse(&self.type_line)
+    }
+
+    /// This card's converted mana cost as a whole number.
+    ///
+    /// Returns `None` if the cost is fractional, which only happens for a few
+    /// funny cards with half mana symbols, such as
+    /// [Little Girl](https://scryfall.com/card/unh/16) with a cost of `{½}`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// assert_eq!(Card::named("Emrakul, the Aeons Torn").unwrap().cmc_int(), Some(15));
+    /// assert_eq!(Card::named("Little Girl").unwrap().cmc_int(), None);
+    /// ```
+    pub fn cmc_int(&self) -> Option<u32> {
+        if self.cmc >= 0.0 && self.cmc.fract() == 0.0 {
+            Some(self.cmc as u32)
+        } else {
+            None
+        }
+    }
+
+    /// This card's converted mana cost, which may be fractional. This is the
+    /// same as the [`cmc`][Card::cmc] field.
+    pub fn cmc_f32(&self) -> f32 {
+        self.cmc
+    }
+
+    /// Parses this card's power, if it has one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, PowTou};
+    /// let goyf = Card::named("Tarmogoyf").unwrap();
+    /// assert_eq!(goyf.power_value(), Some(PowTou::Star));
+    /// assert_eq!(goyf.toughness_value(), Some(PowTou::StarPlus(1)));
+    /// ```
+    pub fn power_value(&self) -> Option<PowTou> {
+        self.power.as_deref().map(PowTou::parse)
+    }
+
+    /// Parses this card's toughness, if it has one.
+    pub fn toughness_value(&self) -> Option<PowTou> {
+        self.toughness.as_deref().map(PowTou::parse)
+    }
+
+    /// Whether Scryfall has information about when and where this card was
+    /// previewed. Most older cards were never previewed, in which case
+    /// [`preview`][Card::preview] is empty.
+    pub fn is_previewed(&self) -> bool {
+        self.preview.previewed_at.is_some()
+            || self.preview.source.is_some()
+            || self.preview.source_uri.is_some()
+    }
+
+    /// The name printed on this card, which is its
+    /// [`flavor_name`][Card::flavor_name] if it has one, such as for Godzilla
+    /// series cards, and its [`name`][Card::name] otherwise.
+    pub fn display_name(&self) -> &str {
+        self.flavor_name.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Checks if `keyword` is one of this card's [`keywords`][Card::keywords],
+    /// ignoring case.
+    ///
+    /// To search for these cards instead, use
+    /// [`keyword`][crate::search::param::value::keyword].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::Card;
+    /// let card = Card::named("Serra Angel").unwrap();
+    /// assert!(card.has_keyword("flying"));
+    /// assert!(!card.has_keyword("trample"));
+    /// ```
+    pub fn has_keyword(&self, keyword: &str) -> bool {
+        self.keywords
+            .iter()
+            .any(|k| k.eq_ignore_ascii_case(keyword.trim()))
+    }
+
+    /// The colors of mana this card can produce, from
+    /// [`produced_mana`][Card::produced_mana]. [`Colors`] can't tell apart
+    /// producing colorless mana from producing none, so check
+    /// [`produces_colorless`][Card::produces_colorless] for that.
+    ///
+    /// To search for these cards instead, use
+    /// [`produces`][crate::search::param::value::produces].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, Colors};
+    /// let card = Card::named("Adarkar Wastes").unwrap();
+    /// assert_eq!(card.produces(), Colors::AZORIUS);
+    /// assert!(card.produces_colorless());
+    /// ```
+    pub fn produces(&self) -> Colors {
+        self.produced_mana.iter().flatten().copied().collect()
+    }
+
+    /// Checks if this card can produce colorless mana, `{C}`.
+    pub fn produces_colorless(&self) -> bool {
+        self.produced_mana
+            .iter()
+            .flatten()
+            .any(|&color| color == Color::Colorless)
+    }
+
+    /// Checks if this card was released before `date`, not counting `date`
+    /// itself.
+    ///
+    /// To search for these cards instead, use
+    /// [`date`][crate::search::param::value::date] with
+    /// [`lt`][crate::search::param::compare::lt].
+    pub fn released_before(&self, date: NaiveDate) -> bool {
+        self.released_at < date
+    }
+
+    /// Checks if this card was released after `date`, not counting `date`
+    /// itself.
+    pub fn released_after(&self, date: NaiveDate) -> bool {
+        self.released_at > date
+    }
+
+    /// Checks if this card was released in `year`. See also
+    /// [`year`][crate::search::param::value::year] for searching.
+    pub fn released_in_year(&self, year: u16) -> bool {
+        self.released_at.year() == i32::from(year)
+    }
+
+    /// Checks if this printing is available in `finish`. If Scryfall didn't
+    /// report any [`finishes`][Card::finishes], the [`foil`][Card::foil] and
+    /// [`nonfoil`][Card::nonfoil] fields are used instead.
+    ///
+    /// To search for etched foils, use
+    /// [`PrintingIs::Etched`][crate::search::param::criteria::PrintingIs::Etched].
+    pub fn has_finish(&self, finish: Finish) -> bool {
+        if !self.finishes.is_empty() {
+            return self.finishes.contains(&finish);
+        }
+        match finish {
+            Finish::Foil => self.foil,
+            Finish::Nonfoil => self.nonfoil,
+            _ => false,
+        }
+    }
+
+    /// The colors in this card's color identity.
+    pub fn color_identity_colors(&self) -> Colors {
+        Colors::from(self.color_identity.as_slice())
+    }
+
+    /// Checks if this card's color identity is within `commander`'s, which
+    /// means it can be included in a Commander deck with that identity.
+    /// Colorless cards fit in every deck.
+    ///
+    /// To search for these cards instead, use
+    /// [`color_identity`][crate::search::param::value::color_identity] with
+    /// [`lte`][crate::search::param::compare::lte].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, Colors};
+    /// let card = Card::named("Sphinx of the Steel Wind").unwrap();
+    /// assert!(card.color_identity_within(Colors::ESPER));
+    /// assert!(!card.color_identity_within(Colors::DIMIR));
+    /// ```
+    pub fn color_identity_within(&self, commander: Colors) -> bool {
+        self.color_identity_colors().is_subset(commander)
+    }
+
+    /// Whether this card print is available in `game`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, Game};
+    /// let cards = Card::search("set:khm t:legendary")
+    ///     .unwrap()
+    ///     .filter_map(Result::ok)
+    ///     .filter(|card| card.available_in(Game::Arena))
+    ///     .collect::<Vec<_>>();
+    /// assert!(cards.iter().all(Card::is_arena));
+    /// ```
+    pub fn available_in(&self, game: Game) -> bool {
+        self.games.contains(&game)
+    }
+
+    /// Whether this card print is available in paper.
+    pub fn is_paper(&self) -> bool {
+        self.available_in(Game::Paper)
+    }
+
+    /// Whether this card print is available on MTG Arena.
+    pub fn is_arena(&self) -> bool {
+        self.available_in(Game::Arena)
+    }
+
+    /// Whether this card print is available on Magic: The Gathering Online.
+    pub fn is_mtgo(&self) -> bool {
+        self.available_in(Game::Mtgo)
+    }
+
+    /// Parses the language code of this printing. Returns `None` for languages
+    /// this crate doesn't know about.
+    pub fn language(&self) -> Option<Language> {
+        self.lang.parse().ok()
+    }
+
+    /// Fetches this printing in another language, using its set code and
+    /// collector number. Returns an error if the printing doesn't exist in
+    /// that language.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, Language};
+    /// let card = Card::set_and_number("war", 1).unwrap();
+    /// let japanese = card.in_language(Language::Japanese).unwrap();
+    /// assert_eq!(japanese.language(), Some(Language::Japanese));
+    /// assert_eq!(japanese.oracle_id, card.oracle_id);
+    /// assert_ne!(japanese.localized_name(), card.name);
+    /// ```
+    pub fn in_language(&self, language: Language) -> crate::Result<Card> {
+        Uri::from(CARDS_URL.join(&format!(
+            "{}/{}/{}",
+            self.set, self.collector_number, language
+        ))?)
+        .fetch()
+    }
+
+    /// The symbols in this card's Oracle text, such as the `{T}` and `{W}` in
+    /// "{T}: Add {W}.", in the order they appear. For cards with several
+    /// faces, the text of each face is used in turn.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, Color, ManaSymbol, OracleSymbol};
+    /// let signet = Card::named("Azorius Signet").unwrap();
+    /// assert_eq!(
+    ///     signet.oracle_text_symbols(),
+    ///     [
+    ///         OracleSymbol::Mana(ManaSymbol::Generic(1)),
+    ///         OracleSymbol::Tap,
+    ///         OracleSymbol::Mana(ManaSymbol::Colored(Color::White)),
+    ///         OracleSymbol::Mana(ManaSymbol::Colored(Color::Blue)),
+    ///     ]
+    /// );
+    /// ```
+    pub fn oracle_text_symbols(&self) -> Vec<OracleSymbol> {
+        let mut symbols = Vec::new();
+        for text in self.oracle_texts() {
+            oracle_symbol::replace_symbols(text, |symbol| {
+                symbols.push(symbol);
+                String::new()
+            });
+        }
+        symbols
+    }
+
+    /// This card's Oracle text, with each symbol replaced by what `render`
+    /// returns for it. For cards with several faces, the text of the faces is
+    /// joined with `\n//\n`.
+    ///
+    /// Returns `None` if this card has no Oracle text.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// let signet = Card::named("Azorius Signet").unwrap();
+    /// let text = signet
+    ///     .render_oracle_text(|symbol| format!("[{}]", symbol))
+    ///     .unwrap();
+    /// assert!(text.starts_with("[{1}], [{T}]: Add [{W}][{U}]."));
+    /// ```
+    pub fn render_oracle_text<F: Fn(&OracleSymbol) -> String>(&self, render: F) -> Option<String> {
+        let texts = self.oracle_texts();
+        if texts.is_empty() {
+            return None;
+        }
+        Some(
+            texts
+                .into_iter()
+                .map(|text| oracle_symbol::replace_symbols(text, |symbol| render(&symbol)))
+                .collect::<Vec<_>>()
+                .join("\n//\n"),
+        )
+    }
+
+    /// The Oracle text of this card, or of each of its faces if the card
+    /// itself has none.
+    fn oracle_texts(&self) -> Vec<&str> {
+        match &self.oracle_text {
+            Some(text) => vec![text.as_str()],
+            None => self
+                .faces()
+                .into_iter()
+                .filter_map(|face| face.oracle_text.as_deref())
+                .collect(),
+        }
+    }
+
+    /// The name printed on this card, or the English name if the printing has
+    /// no localized name.
+    pub fn localized_name(&self) -> &str {
+        self.printed_name.as_deref().unwrap_or(&self.name)
+    }
+
+    /// The type line printed on this card, or the English type line if the
+    /// printing has no localized type line.
+    pub fn localized_type_line(&self) -> &str {
+        self.printed_type_line.as_deref().unwrap_or(&self.type_line)
+    }
+
+    /// The text printed on this card, or the Oracle text if the printing has
+    /// no localized text.
+    pub fn localized_text(&self) -> Option<&str> {
+        self.printed_text.as_deref().or(self.oracle_text.as_deref())
+    }
+
+    /// Parses this card's watermark, if it has one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, Watermark};
+    /// let charm = Card::named("Boros Charm").unwrap();
+    /// assert_eq!(charm.watermark_parsed(), Some(Watermark::Boros));
+    /// ```
+    pub fn watermark_parsed(&self) -> Option<Watermark> {
+        self.watermark.as_deref().map(Watermark::parse)
+    }
+
+    /// The faces of this card, or an empty `Vec` if it only has one face.
+    pub fn faces(&self) -> Vec<&CardFace> {
+        self.card_faces.iter().flatten().collect()
+    }
+
+    /// Checks if this card has a back face. See [`Layout::is_double_faced`].
+    pub fn is_double_faced(&self) -> bool {
+        self.layout.is_double_faced()
+    }
+
+    /// Checks if this card is a split card, such as Fire // Ice.
+    pub fn is_split(&self) -> bool {
+        self.layout == Layout::Split
+    }
+
+    /// Checks if this card is a double-faced card that transforms.
+    pub fn is_transform(&self) -> bool {
+        self.layout == Layout::Transform
+    }
+
+    /// The mana cost of this card, taking its faces into account.
+    ///
+    /// For cards with a back face, only the front face's cost is used, since
+    /// that is the cost of the card while it's not on the battlefield. For
+    /// other cards with several faces, such as split cards, the costs of the
+    /// faces are combined.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// let fire_ice = Card::named("Fire // Ice").unwrap();
+    /// let cost = fire_ice.combined_mana_cost().unwrap();
+    /// assert_eq!(cost.to_string(), "{1}{R}{1}{U}");
+    /// assert_eq!(cost.cmc(), fire_ice.cmc);
+    ///
+    /// let delver = Card::named("Delver of Secrets").unwrap();
+    /// assert_eq!(delver.combined_mana_cost().unwrap().to_string(), "{U}");
+    /// ```
+    pub fn combined_mana_cost(&self) -> crate::Result<ManaCost> {
+        let faces = self.faces();
+        match faces.first() {
+            Some(front) if self.is_double_faced() => ManaCost::parse(&front.mana_cost),
+            Some(_) => ManaCost::parse(
+                &faces
+                    .iter()
+                    .map(|face| face.mana_cost.as_str())
+                    .collect::<String>(),
+            ),
+            None => ManaCost::parse(self.mana_cost.as_deref().unwrap_or_default()),
+        }
+    }
+
+    /// Counts the colored mana symbols in this card's
+    /// [combined mana cost][Card::combined_mana_cost], by color. See
+    /// [`ManaCost::color_pips`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, Color};
+    /// let card = Card::named("Nylea's Disciple").unwrap();
+    /// let pips = card.color_pips().unwrap();
+    /// assert_eq!(pips[&Color::Green], 2);
+    /// assert_eq!(pips.len(), 1);
+    /// ```
+    pub fn color_pips(&self) -> crate::Result<HashMap<Color, usize>> {
+        Ok(self.combined_mana_cost()?.color_pips())
+    }
+
+    /// Fetches the tokens this card creates, as listed in its
+    /// [`all_parts`][Card::all_parts]. Returns an empty `Vec` if the card
+    /// doesn't create tokens.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// let tokens = Card::named("Hordeling Outburst").unwrap().tokens().unwrap();
+    /// assert_eq!(tokens.len(), 1);
+    /// assert!(tokens[0].type_line.contains("Goblin"));
+    /// ```
+    pub fn tokens(&self) -> crate::Result<Vec<Card>> {
+        self.all_parts
+            .iter()
+            .flatten()
+            .filter(|part| part.component == Component::Token)
+            .map(RelatedCard::fetch)
+            .collect()
+    }
+
+    /// Fetches the set this card was printed in, following its `set_uri`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// let card = Card::named("Black Lotus").unwrap();
+    /// let set = card.set_object().unwrap();
+    /// assert_eq!(set.code, card.set);
+    /// assert!(set.card_count > 0);
+    /// ```
+    pub fn set_object(&self) -> crate::Result<Set> {
+        self.set_uri.fetch()
+    }
+
+    /// Returns a [`ListIter`] over every printing of this card, including this
+    /// one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// let bolt = Card::named("Lightning Bolt").unwrap();
+    /// let sets = bolt
+    ///     .prints()
+    ///     .unwrap()
+    ///     .map(|card| card.unwrap().set.to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert!(sets.iter().any(|set| set == "lea"));
+    /// assert!(sets.iter().any(|set| set == "m10"));
+    /// ```
+    pub fn prints(&self) -> crate::Result<ListIter<Card>> {
+        self.prints_search_uri.fetch_iter()
+    }
+
+    /// Returns the printings of this card, keeping only one printing of each
+    /// artwork. See [`ListIter::unique_by_illustration_id`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// let bolt = Card::named("Lightning Bolt").unwrap();
+    /// let prints = bolt.prints().unwrap().count();
+    /// let unique_prints = bolt.unique_prints().unwrap().count();
+    /// assert!(unique_prints < prints);
+    /// ```
+    pub fn unique_prints(&self) -> crate::Result<UniqueCards> {
+        Ok(self.prints()?.unique_by_illustration_id())
+    }
+
+    /// Fetches the printing of this card with the lowest price in `currency`.
+    /// Printings without a price in `currency` are skipped, and if none of
+    /// them have one, an error is returned.
+    ///
+    /// The printings are searched sorted by price, so usually only the first
+    /// page is fetched. Scryfall has no sort order for foil prices, so for
+    /// [`Currency::UsdFoil`] every printing is still checked.
+    ///
+    /// Scryfall only has current prices, so this is the cheapest printing
+    /// right now.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// use scryfall::search::prelude::Currency;
+    /// let card = Card::named("Sol Ring").unwrap();
+    /// let cheapest = card.cheapest_printing(Currency::Usd).unwrap();
+    /// assert_eq!(cheapest.name, "Sol Ring");
+    /// assert!(cheapest.prices.usd_value().is_some());
+    /// ```
+    pub fn cheapest_printing(&self, currency: Currency) -> crate::Result<Card> {
+        let mut priced =
+            self.prints_by_price(currency)?
+                .fetch_iter()?
+                .filter_map(|card| match card {
+                    Ok(card) => card
+                        .prices
+                        .value_in(currency)
+                        .map(|price| Ok((price, card))),
+                    Err(e) => Some(Err(e)),
+                });
+        let cheapest = match currency {
+            Currency::UsdFoil => priced
+                .collect::<crate::Result<Vec<_>>>()?
+                .into_iter()
+                .reduce(|cheapest, next| if next.0 < cheapest.0 { next } else { cheapest }),
+            _ => priced.next().transpose()?,
+        };
+        cheapest.map(|(_, card)| card).ok_or_else(|| {
+            Error::Other(format!(
+                "No printing of {} has a {} price",
+                self.name, currency
+            ))
+        })
+    }
+
+    /// The [`prints_search_uri`][Card::prints_search_uri] of this card, sorted
+    /// from the lowest price in `currency` to the highest, with unpriced
+    /// printings last.
+    fn prints_by_price(&self, currency: Currency) -> crate::Result<Uri<List<Card>>> {
+        let order = match currency {
+            Currency::Usd | Currency::UsdFoil => SortOrder::Usd,
+            Currency::Eur => SortOrder::Eur,
+            Currency::Tix => SortOrder::Tix,
+        };
+        let mut url = self.prints_search_uri.as_url().clone();
+        let pairs: Vec<(String, String)> = url
+            .query_pairs()
+            .into_owned()
+            .filter(|(key, _)| key != "order" && key != "dir")
+            .collect();
+        url.query_pairs_mut().clear().extend_pairs(pairs);
+        [("order", order)].serialize(serde_urlencoded::Serializer::new(
+            &mut url.query_pairs_mut(),
+        ))?;
+        Ok(Uri::from(url))
+    }
+
+    /// Fetches the rulings for this card.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// let rulings = Card::named("Blood Moon").unwrap().rulings().unwrap();
+    /// assert!(rulings.iter().any(|r| r.comment.contains("layer")));
+    /// ```
+    pub fn rulings(&self) -> crate::Result<Vec<Ruling>> {
+        self.rulings_uri.fetch_all()
+    }
+
+    /// The URI of this card's image in the given `version`.
+    ///
+    /// Double-faced cards don't have images at the top level, so the images of
+    /// their front face are used instead.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, ImageVersion};
+    /// let delver = Card::named("Delver of Secrets").unwrap();
+    /// assert!(delver.image_uris.is_empty());
+    /// assert!(delver.image_uri(ImageVersion::Png).unwrap().ends_with(".png"));
+    /// ```
+    pub fn image_uri(&self, version: ImageVersion) -> Option<&str> {
+        match self.image_uris.get(&version.to_string()) {
+            Some(uri) => Some(uri.as_str()),
+            None => self.card_faces.as_ref()?.first()?.image_uri(version),
+        }
+    }
+
+    /// Downloads this card's image in the given `version`. See
+    /// [`image_uri`][Card::image_uri]. To download the image of another face
+    /// of a double-faced card, use [`CardFace::fetch_image`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, ImageVersion};
+    /// let delver = Card::named("Delver of Secrets").unwrap();
+    /// let png = delver.fetch_image(ImageVersion::Png).unwrap();
+    /// assert_eq!(&png[1..4], b"PNG");
+    /// ```
+    pub fn fetch_image(&self, version: ImageVersion) -> crate::Result<Vec<u8>> {
+        let uri = self
+            .image_uri(version)
+            .ok_or_else(|| Error::Other(format!("{} has no {} image", self.name, version)))?;
+        download_image(uri)
+    }
+
+    /// A link to this card's set on Scryfall's website. This is the
+    /// [`scryfall_set_uri`][Card::scryfall_set_uri] field, parsed.
+    pub fn scryfall_set_url(&self) -> crate::Result<Url> {
+        Ok(Url::parse(&self.scryfall_set_uri)?)
+    }
+
+    /// A link to this card's listing on a marketplace, such as `"tcgplayer"`
+    /// or `"cardmarket"`. Returns `None` if there is no link for
+    /// `marketplace`, or if it isn't a valid URL.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// let bolt = Card::named("Lightning Bolt").unwrap();
+    /// let url = bolt.purchase_url("tcgplayer").unwrap();
+    /// assert!(url.host_str().unwrap().contains("tcgplayer"));
+    /// assert_eq!(bolt.purchase_url("not a marketplace"), None);
+    /// ```
+    pub fn purchase_url(&self, marketplace: &str) -> Option<Url> {
+        Url::parse(self.purchase_uris.get(marketplace)?).ok()
+    }
+
+    /// A link to this card on another Magic resource, such as `"gatherer"` or
+    /// `"edhrec"`. Returns `None` if there is no link for `resource`, or if it
+    /// isn't a valid URL.
+    pub fn related_url(&self, resource: &str) -> Option<Url> {
+        Url::parse(self.related_uris.get(resource)?).ok()
+    }
+
+    /// The legality of this card in `format`. Formats Scryfall didn't report a
+    /// legality for are treated as [`Legality::NotLegal`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, Legality};
+    /// use scryfall::format::Format;
+    /// let lotus = Card::named("Black Lotus").unwrap();
+    /// assert_eq!(lotus.legality_in(Format::Vintage), Legality::Restricted);
+    /// assert_eq!(lotus.legality_in(Format::Legacy), Legality::Banned);
+    /// ```
+    pub fn legality_in(&self, format: Format) -> Legality {
+        self.legalities
+            .get(&format)
+            .copied()
+            .unwrap_or(Legality::NotLegal)
+    }
+
+    /// Checks if this card can be played in `format`, that is, if it is either
+    /// legal or restricted.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// use scryfall::format::Format;
+    /// let bolt = Card::named("Lightning Bolt").unwrap();
+    /// assert!(bolt.is_legal(Format::Modern));
+    /// assert!(!bolt.is_legal(Format::Standard));
+    /// ```
+    pub fn is_legal(&self, format: Format) -> bool {
+        matches!(
+            self.legality_in(format),
+            Legality::Legal | Legality::Restricted
+        )
+    }
+}
+
+/// Asynchronous versions of the functions that fetch cards. They require the
+/// `async` feature, and must be run by a [Tokio](https://tokio.rs) runtime.
+#[cfg(feature = "async")]
+impl Card {
+    /// Like [`Card::named`], but asynchronous.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use scryfall::card::Card;
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() -> scryfall::Result<()> {
+    /// let bolt = Card::named_async("Lightning Bolt").await?;
+    /// assert_eq!(bolt.name, "Lightning Bolt");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn named_async(name: &str) -> crate::Result<Card> {
+        crate::client::named_uri("exact", name)?.fetch_async().await
+    }
+
+    /// Like [`Card::search`], but asynchronous, returning a [`Stream`] of the
+    /// cards that match the search terms.
+    ///
+    /// The first page is requested before returning, so errors with the query
+    /// are reported right away. Following pages are requested once the
+    /// previous page has been taken from the stream.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use futures::TryStreamExt;
+    /// use scryfall::search::prelude::*;
+    /// use scryfall::Card;
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() -> scryfall::Result<()> {
+    /// let goblins: Vec<Card> = Card::search_async(type_line("goblin"))
+    ///     .await?
+    ///     .try_collect()
+    ///     .await?;
+    /// assert!(goblins.iter().all(|card| card.type_line.contains("Goblin")));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_async(
+        query: impl Search,
+    ) -> crate::Result<impl Stream<Item = crate::Result<Card>>> {
+        let first = crate::client::search_uri(&query)?.fetch_async().await?;
+        let pages = page_stream(NextPage::Ready(first))
+            .take(query.max_pages().unwrap_or(usize::MAX))
+            .map_ok(|page| stream::iter(page.data.into_iter().map(Ok)));
+        Ok(pages.try_flatten())
+    }
+}
+
+/// The maximum number of identifiers accepted by a single collection request.
+const COLLECTION_MAX_IDENTIFIERS: usize = 75;
+
+#[derive(Serialize)]
+struct CollectionRequest<'a> {
+    identifiers: &'a [CardIdentifier],
+}
+
+#[derive(Deserialize)]
+struct Collection {
+    data: Vec<Card>,
+    #[serde(default)]
+    not_found: Vec<CardIdentifier>,
+}
+
+/// Pairs each identifier with its card. Scryfall returns the found cards and
+/// the identifiers that were not found in the same order they were requested
+/// in, echoing the missing identifiers back.
+///
+/// An identifier that is the next missing one gets `None`, and every other
+/// identifier gets the next card. The cards themselves aren't compared with
+/// the identifiers, since Scryfall also finds cards whose names differ in
+/// accents or punctuation. Once the cards run out, the remaining identifiers
+/// are the missing ones.
+fn collate<T>(
+    identifiers: &[CardIdentifier],
+    data: Vec<T>,
+    not_found: Vec<CardIdentifier>,
+) -> crate::Result<Vec<Option<T>>> {
+    if data.len() + not_found.len() != identifiers.len() {
+        return Err(Error::Other(format!(
+            "Scryfall returned {} cards and {} missing identifiers for {} identifiers",
+            data.len(),
+            not_found.len(),
+            identifiers.len()
+        )));
+    }
+    let mut data = data.into_iter();
+    let mut not_found = not_found.into_iter().peekable();
+    Ok(identifiers
+        .iter()
+        .map(|identifier| {
+            let missing = not_found
+                .peek()
+                .is_some_and(|missing| identifier.same_as(missing));
+            if missing || data.len() == 0 {
+                not_found.next();
+                None
+            } else {
+                data.next()
+            }
+        })
+        .collect())
+}
+
+/// Downloads the image at `uri`.
+fn download_image(uri: &str) -> crate::Result<Vec<u8>> {
+    let mut image = Vec::new();
+    Uri::<()>::try_from(uri)?
+        .fetch_raw()?
+        .into_reader()
+        .read_to_end(&mut image)?;
+    Ok(image)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    #[test]
+    fn collate_collection() {
+        let name = |n: &str| CardIdentifier::Name(n.to_string());
+        let identifiers = vec![name("a"), name("b"), name("c"), name("b"), name("d")];
+
+        assert_eq!(
+            collate(
+                &identifiers,
+                vec!["a", "c", "b"],
+                vec![name("b"), name("d")]
+            )
+            .unwrap(),
+            vec![Some("a"), None, Some("c"), Some("b"), None],
+        );
+        assert_eq!(
+            collate(&identifiers, vec!["a", "b", "c", "b", "d"], vec![]).unwrap(),
+            vec![Some("a"), Some("b"), Some("c"), Some("b"), Some("d")],
+        );
+
+        // Scryfall doesn't echo the missing identifiers exactly.
+        assert_eq!(
+            collate(
+                &identifiers,
+                vec!["A", "C", "b"],
+                vec![name("B"), name("D")]
+            )
+            .unwrap(),
+            vec![Some("A"), None, Some("C"), Some("b"), None],
+        );
+        let set = |n: &str, s: &str| CardIdentifier::NameSet {
+            name: n.to_string(),
+            set: s.to_string(),
+        };
+        assert_eq!(
+            collate(
+                &[set("x", "m10"), set("a", "war")],
+                vec!["a"],
+                vec![set("X", "M10")]
+            )
+            .unwrap(),
+            vec![None, Some("a")],
+        );
+
+        // Found cards are taken in order, even when their names differ.
+        assert_eq!(
+            collate(
+                &[name("Juzam Djinn"), name("Not a card")],
+                vec!["Juzám Djinn"],
+                vec![name("Not a card")]
+            )
+            .unwrap(),
+            vec![Some("Juzám Djinn"), None],
+        );
+        assert_eq!(
+            collate(&[name("a"), name("b")], vec!["c"], vec![name("d")]).unwrap(),
+            vec![Some("c"), None],
+        );
+        // The counts don't add up.
+        assert!(collate(&identifiers, vec!["a"], vec![name("b")]).is_err());
+    }
+
+    /// A minimal card, for tests to deserialize after changing its fields.
+    pub(crate) fn card_json() -> serde_json::Value {
+        let id = "56ebc372-aabd-4174-a943-c7bf59e5028d";
+        let api = "https://api.scryfall.com";
+        serde_json::json!({
+            "id": id,
+            "lang": "en",
+            "oracle_id": id,
+            "prints_search_uri": format!("{}/cards/search?q=oracleid%3A{}", api, id),
+            "rulings_uri": format!("{}/cards/{}/rulings", api, id),
+            "scryfall_uri": "https://scryfall.com/card/m21/1/card",
+            "uri": format!("{}/cards/{}", api, id),
+            "cmc": 1.0,
+            "color_identity": ["W"],
+            "foil": true,
+            "keywords": [],
+            "layout": "normal",
+            "legalities": {},
+            "name": "Card",
+            "nonfoil": true,
+            "oversized": false,
+            "reserved": false,
+            "type_line": "Creature",
+            "booster": true,
+            "border_color": "black",
+            "card_back_id": id,
+            "collector_number": "1",
+            "digital": false,
+            "frame": "2015",
+            "full_art": false,
+            "games": ["paper"],
+            "highres_image": true,
+            "promo": false,
+            "rarity": "common",
+            "related_uris": {},
+            "released_at": "2020-07-03",
+            "reprint": false,
+            "scryfall_set_uri": "https://scryfall.com/sets/m21",
+            "set_name": "Core Set 2021",
+            "set_search_uri": format!("{}/cards/search?q=e%3Am21", api),
+            "set_type": "core",
+            "set_uri": format!("{}/sets/m21", api),
+            "set": "m21",
+            "story_spotlight": false,
+            "textless": false,
+            "variation": false,
+        })
+    }
+
+    /// Deserializes [`card_json`] after `f` changes its fields.
+    pub(crate) fn card_with(f: impl FnOnce(&mut serde_json::Value)) -> Card {
+        let mut json = card_json();
+        f(&mut json);
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn deserialize_unknown_enum_values() {
+        let card = card_with(|json| {
+            json["rarity"] = "legendary".into();
+            json["frame"] = "2030".into();
+            json["frame_effects"] = serde_json::json!(["legendary", "hologram"]);
+            json["games"] = serde_json::json!(["paper", "hologame"]);
+            json["layout"] = "triple_faced".into();
+            json["set_type"] = "hologram".into();
+        });
+        assert_eq!(card.rarity, Rarity::Unknown("legendary".to_string()));
+        assert_eq!(card.frame, Frame::Unknown("2030".to_string()));
+        assert_eq!(
+            card.frame_effects,
+            [
+                FrameEffect::Legendary,
+                FrameEffect::Unknown("hologram".to_string())
+            ]
+        );
+        assert_eq!(
+            card.games,
+            [Game::Paper, Game::Unknown("hologame".to_string())]
+        );
+        assert_eq!(card.layout, Layout::Unknown("triple_faced".to_string()));
+        assert_eq!(card.set_type, SetType::Unknown("hologram".to_string()));
+
+        let json = serde_json::to_value(&card).unwrap();
+        assert_eq!(json["rarity"], "legendary");
+        assert_eq!(json["frame"], "2030");
+        assert_eq!(
+            json["frame_effects"],
+            serde_json::json!(["legendary", "hologram"])
+        );
+        assert_eq!(json["games"], serde_json::json!(["paper", "hologame"]));
+        assert_eq!(json["layout"], "triple_faced");
+        assert_eq!(json["set_type"], "hologram");
+        assert_eq!(serde_json::from_value::<Card>(json).unwrap(), card);
+    }
+
+    #[test]
+    fn commander_color_identity() {
+        let card = card_with(|json| {
+            json["color_identity"] = serde_json::json!(["W", "B"]);
+        });
+        assert_eq!(card.color_identity_colors(), Colors::ORZHOV);
+        assert!(card.color_identity_within(Colors::ORZHOV));
+        assert!(card.color_identity_within(Colors::MARDU));
+        assert!(!card.color_identity_within(Colors::BOROS));
+
+        let card = card_with(|json| {
+            json["color_identity"] = serde_json::json!([]);
+        });
+        assert!(card.color_identity_within(Colors::COLORLESS));
+    }
+
+    #[test]
+    fn flavor_names() {
+        let card = card_with(|_| {});
+        assert_eq!(card.display_name(), "Card");
+
+        let card = card_with(|json| {
+            json["name"] = "Zilortha, Strength Incarnate".into();
+            json["flavor_name"] = "Godzilla, King of the Monsters".into();
+        });
+        assert_eq!(card.display_name(), "Godzilla, King of the Monsters");
+    }
+
+    #[test]
+    fn release_dates() {
+        let card = card_with(|_| {});
+        let day = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert!(card.released_before(day(2020, 7, 4)));
+        assert!(!card.released_before(day(2020, 7, 3)));
+        assert!(card.released_after(day(2020, 7, 2)));
+        assert!(!card.released_after(day(2020, 7, 3)));
+        assert!(card.released_in_year(2020));
+        assert!(!card.released_in_year(2021));
+    }
+
+    #[test]
+    fn card_finishes() {
+        let card = card_with(|_| {});
+        assert!(card.finishes.is_empty());
+        assert!(card.has_finish(Finish::Foil));
+        assert!(card.has_finish(Finish::Nonfoil));
+        assert!(!card.has_finish(Finish::Etched));
+
+        let card = card_with(|json| {
+            json["finishes"] = serde_json::json!(["nonfoil", "etched", "hologram"]);
+        });
+        assert_eq!(
+            card.finishes,
+            [
+                Finish::Nonfoil,
+                Finish::Etched,
+                Finish::Unknown("hologram".to_string())
+            ]
+        );
+        assert!(card.has_finish(Finish::Etched));
+        assert!(!card.has_finish(Finish::Foil));
+    }
+
+    #[test]
+    fn produced_mana() {
+        let card = card_with(|_| {});
+        assert_eq!(card.produces(), Colors::COLORLESS);
+        assert!(!card.produces_colorless());
+
+        let card = card_with(|json| {
+            json["produced_mana"] = serde_json::json!(["C", "U", "W"]);
+        });
+        assert_eq!(card.produces(), Colors::AZORIUS);
+        assert!(card.produces_colorless());
+    }
+
+    #[test]
+    fn face_images() {
+        let face = |name: &str, side: &str| {
+            let image =
+                |version: &str| format!("https://cards.scryfall.io/{}/{}/card.jpg", version, side);
+            serde_json::json!({
+                "name": name,
+                "mana_cost": "",
+                "type_line": "Creature",
+                "image_uris": {
+                    "normal": image("normal"),
+                    "small": image("small"),
+                },
+            })
+        };
+        let card = card_with(|json| {
+            json["layout"] = "transform".into();
+            json["card_faces"] = serde_json::json!([face("Front", "front"), face("Back", "back")]);
+        });
+        let faces = card.faces();
+        let front = faces[0].image_uri(ImageVersion::Normal).unwrap();
+        let back = faces[1].image_uri(ImageVersion::Normal).unwrap();
+        assert_ne!(front, back);
+        assert!(back.contains("/back/"));
+        assert_eq!(card.image_uri(ImageVersion::Normal), Some(front));
+        assert_eq!(card.image_uri(ImageVersion::Png), None);
+        assert!(card.fetch_image(ImageVersion::Png).is_err());
+        assert!(faces[1].fetch_image(ImageVersion::Png).is_err());
+
+        let card = card_with(|json| {
+            json["image_uris"] =
+                serde_json::json!({ "normal": "https://cards.scryfall.io/normal/card.jpg" });
+        });
+        assert_eq!(
+            card.image_uri(ImageVersion::Normal),
+            Some("https://cards.scryfall.io/normal/card.jpg")
+        );
+    }
+
+    #[test]
+    fn oracle_text_symbols() {
+        let card = card_with(|json| {
+            json["oracle_text"] = "{T}, Pay {E}{E}: Add {C}.".into();
+        });
+        assert_eq!(
+            card.oracle_text_symbols(),
+            [
+                OracleSymbol::Tap,
+                OracleSymbol::Energy,
+                OracleSymbol::Energy,
+                OracleSymbol::Mana(ManaSymbol::Colored(Color::Colorless)),
+            ]
+        );
+        let rendered = card.render_oracle_text(|symbol| match symbol {
+            OracleSymbol::Tap => "(tap)".to_string(),
+            OracleSymbol::Energy => "(energy)".to_string(),
+            other => other.to_string(),
+        });
+        assert_eq!(
+            rendered.as_deref(),
+            Some("(tap), Pay (energy)(energy): Add {C}.")
+        );
+
+        let card = card_with(|_| {});
+        assert!(card.oracle_text_symbols().is_empty());
+        assert_eq!(card.render_oracle_text(|symbol| symbol.to_string()), None);
+    }
+
+    #[test]
+    fn prints_sorted_by_price() {
+        let card = card_with(|json| {
+            json["prints_search_uri"] =
+                "https://api.scryfall.com/cards/search?order=released&q=oracleid%3A0&unique=prints"
+                    .into();
+        });
+        let matrix = vec![
+            (Currency::Usd, "usd"),
+            (Currency::UsdFoil, "usd"),
+            (Currency::Eur, "eur"),
+            (Currency::Tix, "tix"),
+        ];
+        for (currency, order) in matrix {
+            assert_eq!(
+                card.prints_by_price(currency).unwrap().as_str(),
+                format!(
+                    "https://api.scryfall.com/cards/search?q=oracleid%3A0&unique=prints&order={}",
+                    order
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn card_keywords() {
+        let card = card_with(|json| {
+            json.as_object_mut().unwrap().remove("keywords");
+        });
+        assert!(card.keywords.is_empty());
+        assert!(!card.has_keyword("flying"));
+
+        let card = card_with(|json| {
+            json["keywords"] = serde_json::json!(["Flying", "Cumulative upkeep"]);
+        });
+        assert!(card.has_keyword("flying"));
+        assert!(card.has_keyword("Cumulative Upkeep"));
+        assert!(!card.has_keyword("upkeep"));
+    }
+
+    #[test]
+    fn card_type_lines() {
+        let card = card_with(|_| {});
+        assert_eq!(card.parsed_type_line().unwrap().card_types, ["Creature"]);
+
+        let card = card_with(|json| {
+            json["type_line"] = serde_json::json!("Instant — Adventure // Creature — Elf");
+        });
+        let type_line = card.parsed_type_line().unwrap();
+        assert_eq!(type_line.card_types, ["Instant", "Creature"]);
+        assert_eq!(type_line.subtypes, ["Adventure", "Elf"]);
+
+        let card = card_with(|json| {
+            json["type_line"] = serde_json::json!("");
+        });
+        assert_eq!(card.parsed_type_line(), None);
+    }
+
+    #[test]
+    fn typed_urls() {
+        let card = card_with(|json| {
+            json["purchase_uris"] = serde_json::json!({
+                "tcgplayer": "https://shop.tcgplayer.com/product/productsearch?id=1",
+                "broken": "not a url",
+            });
+            json["related_uris"] = serde_json::json!({
+                "edhrec": "https://edhrec.com/route/?cc=Card",
+            });
+        });
+        assert_eq!(
+            card.scryfall_set_url().unwrap().as_str(),
+            "https://scryfall.com/sets/m21"
+        );
+        assert_eq!(
+            card.purchase_url("tcgplayer").unwrap().host_str(),
+            Some("shop.tcgplayer.com")
+        );
+        assert_eq!(card.purchase_url("broken"), None);
+        assert_eq!(card.purchase_url("cardmarket"), None);
+        assert_eq!(
+            card.related_url("edhrec").unwrap().host_str(),
+            Some("edhrec.com")
+        );
+    }
+
+    #[test]
+    fn deserialize_preview() {
+        let card = card_with(|_| {});
+        assert!(!card.is_previewed());
+        assert_eq!(card.preview, Preview::default());
+
+        let card = card_with(|json| {
+            json["preview"] = serde_json::json!({
+                "source": "Wizards of the Coast",
+                "source_uri": "",
+                "previewed_at": "2020-06-08",
+            });
+        });
+        assert!(card.is_previewed());
+        assert_eq!(
+            card.preview.previewed_at,
+            NaiveDate::from_ymd_opt(2020, 6, 8)
+        );
+        assert_eq!(card.preview.source.as_deref(), Some("Wizards of the Coast"));
+        assert_eq!(card.preview.source_uri, None);
     }
 }
@@ -1,12 +1,15 @@
 //! Enum defining the colors a mtg card border can have.
 use serde::{Deserialize, Serialize};
 
+use crate::util::from_str_by_display;
+
 /// Enum defining the colors a mtg card border can have.
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Serialize, Deserialize, Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
 #[serde(rename_all = "snake_case")]
 #[allow(missing_docs)]
 #[non_exhaustive]
 pub enum BorderColor {
+    #[default]
     Black,
     Borderless,
     Gold,
@@ -14,12 +17,6 @@ pub enum BorderColor {
     Silver,
 }
 
-impl Default for BorderColor {
-    fn default() -> Self {
-        BorderColor::Black
-    }
-}
-
 impl std::fmt::Display for BorderColor {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         use BorderColor::*;
@@ -36,3 +33,9 @@ impl std::fmt::Display for BorderColor {
         )
     }
 }
+
+from_str_by_display! {
+    BorderColor, "border color", [
+        Black, Borderless, Gold, White, Silver,
+    ]
+}
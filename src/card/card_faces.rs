@@ -1,9 +1,10 @@
 use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
-use crate::card::Color;
+use crate::card::{Color, ImageVersion};
+use crate::error::Error;
+use crate::util::Uuid;
 
 /// Multiface cards have a card_faces property containing at least two Card Face
 /// objects.
@@ -60,10 +61,10 @@ pub struct CardFace {
     /// The localized text printed on this face, if any.
     pub printed_text: Option<String>,
 
-    ///
+    /// The localized type line printed on this face, if any.
     pub printed_type_line: Option<String>,
 
-    /// The localized type line printed on this face, if any.
+    /// This face’s toughness, if any.
     pub toughness: Option<String>,
 
     /// The type line of this particular face.
@@ -72,3 +73,34 @@ pub struct CardFace {
     /// The watermark on this particulary card face, if any.
     pub watermark: Option<String>,
 }
+
+impl CardFace {
+    /// The URI of this face's image in the given `version`. Only the faces of
+    /// double-sided cards have their own images.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, ImageVersion};
+    /// let delver = Card::named("Delver of Secrets").unwrap();
+    /// let faces = delver.faces();
+    /// assert_ne!(
+    ///     faces[0].image_uri(ImageVersion::Normal),
+    ///     faces[1].image_uri(ImageVersion::Normal)
+    /// );
+    /// ```
+    pub fn image_uri(&self, version: ImageVersion) -> Option<&str> {
+        self.image_uris
+            .as_ref()?
+            .get(&version.to_string())
+            .map(String::as_str)
+    }
+
+    /// Downloads this face's image in the given `version`. See
+    /// [`image_uri`][CardFace::image_uri].
+    pub fn fetch_image(&self, version: ImageVersion) -> crate::Result<Vec<u8>> {
+        let uri = self
+            .image_uri(version)
+            .ok_or_else(|| Error::Other(format!("{} has no {} image", self.name, version)))?;
+        super::download_image(uri)
+    }
+}
@@ -0,0 +1,209 @@
+//! Identifiers used to request many cards at once from the collection
+//! endpoint.
+use std::convert::TryFrom;
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::Uuid;
+
+/// An identifier for a card, used with
+/// [`Card::collection`][crate::card::Card::collection] to fetch many cards in
+/// a single request.
+///
+/// For more information, refer to the [official docs](https://scryfall.com/docs/api/cards/collection).
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
+#[serde(into = "IdentifierRepr", try_from = "IdentifierRepr")]
+pub enum CardIdentifier {
+    /// Finds a card with the specified Scryfall ID.
+    Id(Uuid),
+    /// Finds a card with the specified MTGO ID or MTGO foil ID.
+    MtgoId(usize),
+    /// Finds a card with the specified value among its multiverse IDs.
+    MultiverseId(usize),
+    /// Finds the newest edition of a card with the specified name.
+    Name(String),
+    /// Finds a card matching the specified name and set.
+    NameSet {
+        /// The name of the card.
+        name: String,
+        /// The set code of the printing.
+        set: String,
+    },
+    /// Finds a card with the specified set and collector number.
+    CollectorNumberSet {
+        /// The collector number of the printing.
+        collector_number: String,
+        /// The set code of the printing.
+        set: String,
+    },
+}
+
+impl CardIdentifier {
+    /// Checks if `other` is this identifier, ignoring the case of names, set
+    /// codes and collector numbers.
+    pub(crate) fn same_as(&self, other: &CardIdentifier) -> bool {
+        let eq = |a: &str, b: &str| a.eq_ignore_ascii_case(b);
+        match (self, other) {
+            (CardIdentifier::Name(a), CardIdentifier::Name(b)) => eq(a, b),
+            (
+                CardIdentifier::NameSet { name, set },
+                CardIdentifier::NameSet {
+                    name: other_name,
+                    set: other_set,
+                },
+            ) => eq(name, other_name) && eq(set, other_set),
+            (
+                CardIdentifier::CollectorNumberSet {
+                    collector_number,
+                    set,
+                },
+                CardIdentifier::CollectorNumberSet {
+                    collector_number: other_number,
+                    set: other_set,
+                },
+            ) => eq(collector_number, other_number) && eq(set, other_set),
+            (a, b) => a == b,
+        }
+    }
+}
+
+/// The JSON object Scryfall uses to represent a [`CardIdentifier`].
+#[derive(Serialize, Deserialize, Default)]
+struct IdentifierRepr {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mtgo_id: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    multiverse_id: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    collector_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    set: Option<String>,
+}
+
+impl From<CardIdentifier> for IdentifierRepr {
+    fn from(identifier: CardIdentifier) -> Self {
+        match identifier {
+            CardIdentifier::Id(id) => IdentifierRepr {
+                id: Some(id),
+                ..Default::default()
+            },
+            CardIdentifier::MtgoId(mtgo_id) => IdentifierRepr {
+                mtgo_id: Some(mtgo_id),
+                ..Default::default()
+            },
+            CardIdentifier::MultiverseId(multiverse_id) => IdentifierRepr {
+                multiverse_id: Some(multiverse_id),
+                ..Default::default()
+            },
+            CardIdentifier::Name(name) => IdentifierRepr {
+                name: Some(name),
+                ..Default::default()
+            },
+            CardIdentifier::NameSet { name, set } => IdentifierRepr {
+                name: Some(name),
+                set: Some(set),
+                ..Default::default()
+            },
+            CardIdentifier::CollectorNumberSet {
+                collector_number,
+                set,
+            } => IdentifierRepr {
+                collector_number: Some(collector_number),
+                set: Some(set),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl TryFrom<IdentifierRepr> for CardIdentifier {
+    type Error = &'static str;
+
+    fn try_from(repr: IdentifierRepr) -> Result<Self, Self::Error> {
+        Ok(match repr {
+            IdentifierRepr { id: Some(id), .. } => CardIdentifier::Id(id),
+            IdentifierRepr {
+                mtgo_id: Some(mtgo_id),
+                ..
+            } => CardIdentifier::MtgoId(mtgo_id),
+            IdentifierRepr {
+                multiverse_id: Some(multiverse_id),
+                ..
+            } => CardIdentifier::MultiverseId(multiverse_id),
+            IdentifierRepr {
+                collector_number: Some(collector_number),
+                set: Some(set),
+                ..
+            } => CardIdentifier::CollectorNumberSet {
+                collector_number,
+                set,
+            },
+            IdentifierRepr {
+                name: Some(name),
+                set: Some(set),
+                ..
+            } => CardIdentifier::NameSet { name, set },
+            IdentifierRepr {
+                name: Some(name), ..
+            } => CardIdentifier::Name(name),
+            _ => return Err("unrecognized card identifier"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_str, json, to_value};
+
+    use super::*;
+
+    #[test]
+    fn identifier_serde() {
+        let matrix = vec![
+            (
+                CardIdentifier::Id("683a5707-cddb-494d-9b41-51b4584ded69".parse().unwrap()),
+                json!({ "id": "683a5707-cddb-494d-9b41-51b4584ded69" }),
+            ),
+            (CardIdentifier::MtgoId(54957), json!({ "mtgo_id": 54957 })),
+            (
+                CardIdentifier::MultiverseId(409574),
+                json!({ "multiverse_id": 409574 }),
+            ),
+            (
+                CardIdentifier::Name("Ancient Tomb".to_string()),
+                json!({ "name": "Ancient Tomb" }),
+            ),
+            (
+                CardIdentifier::NameSet {
+                    name: "Lightning Bolt".to_string(),
+                    set: "m10".to_string(),
+                },
+                json!({ "name": "Lightning Bolt", "set": "m10" }),
+            ),
+            (
+                CardIdentifier::CollectorNumberSet {
+                    collector_number: "150".to_string(),
+                    set: "mrd".to_string(),
+                },
+                json!({ "collector_number": "150", "set": "mrd" }),
+            ),
+        ];
+
+        for (identifier, value) in matrix {
+            assert_eq!(to_value(&identifier).unwrap(), value);
+            assert_eq!(
+                from_str::<CardIdentifier>(&value.to_string()).unwrap(),
+                identifier
+            );
+        }
+    }
+
+    #[test]
+    fn empty_identifier() {
+        assert!(from_str::<CardIdentifier>("{}").is_err());
+    }
+}
@@ -0,0 +1,64 @@
+//! Enum defining the common card types.
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::from_str_by_display;
+
+/// The card types that appear before the em dash in a type line. This enum
+/// can be passed to [`type_line()`][crate::search::param::value::type_line]
+/// instead of free text.
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card::CardType;
+/// use scryfall::search::prelude::*;
+/// let query = type_line(CardType::Creature).and(type_line("elf"));
+/// assert_eq!(query.to_string(), "(type:creature AND type:\"elf\")");
+/// ```
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)]
+#[non_exhaustive]
+pub enum CardType {
+    Artifact,
+    Battle,
+    Creature,
+    Enchantment,
+    Instant,
+    Kindred,
+    Land,
+    Planeswalker,
+    Sorcery,
+    /// The old name for [`Kindred`][CardType::Kindred], still used by Scryfall
+    /// as a synonym.
+    Tribal,
+}
+
+impl fmt::Display for CardType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                CardType::Artifact => "artifact",
+                CardType::Battle => "battle",
+                CardType::Creature => "creature",
+                CardType::Enchantment => "enchantment",
+                CardType::Instant => "instant",
+                CardType::Kindred => "kindred",
+                CardType::Land => "land",
+                CardType::Planeswalker => "planeswalker",
+                CardType::Sorcery => "sorcery",
+                CardType::Tribal => "tribal",
+            }
+        )
+    }
+}
+
+from_str_by_display! {
+    CardType, "card type", [
+        Artifact, Battle, Creature, Enchantment, Instant, Kindred, Land,
+        Planeswalker, Sorcery, Tribal,
+    ]
+}
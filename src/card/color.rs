@@ -1,14 +1,19 @@
 use std::fmt;
+use std::iter::FromIterator;
+use std::ops::{BitAnd, BitOr, BitXor, Sub};
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
 use self::Color::*;
+use crate::util::from_str_by_display;
 
 /// Enum defining the 5 colors of magic, plus colorless.
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Serialize, Deserialize, Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
 #[allow(missing_docs)]
 #[repr(u8)]
 pub enum Color {
+    #[default]
     #[serde(rename = "C")]
     Colorless = 0,
     #[serde(rename = "W")]
@@ -23,6 +28,13 @@ pub enum Color {
     Green = 1 << 4,
 }
 
+impl Color {
+    /// The five colors of magic, in WUBRG order.
+    pub const fn all() -> [Color; 5] {
+        [White, Blue, Black, Red, Green]
+    }
+}
+
 impl fmt::Display for Color {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -40,10 +52,10 @@ impl fmt::Display for Color {
     }
 }
 
-impl Default for Color {
-    fn default() -> Self {
-        Colorless
-    }
+from_str_by_display! {
+    Color, "color", [
+        Colorless, White, Blue, Black, Red, Green,
+    ]
 }
 
 /// Definition of a cards colors. This can be used in conjunction with
@@ -222,6 +234,12 @@ impl Colors {
         self.0 & color as u8 != 0
     }
 
+    /// Checks if this instance contains a certain color. Same as
+    /// [`is`][Colors::is].
+    pub const fn contains(self, color: Color) -> bool {
+        self.is(color)
+    }
+
     /// Checks if this instance is multicolored, which is true if it contains
     /// more than one color flag.
     pub const fn is_multicolored(self) -> bool {
@@ -233,6 +251,12 @@ impl Colors {
         self.0 == Colorless as u8
     }
 
+    /// Checks if every color in this instance is also in `other`. Colorless is
+    /// a subset of every instance.
+    pub const fn is_subset(self, other: Colors) -> bool {
+        self.0 & !other.0 == 0
+    }
+
     /// Produces a new instance with all the colors from both `self` and
     /// `other`.
     pub const fn union(self, other: Colors) -> Self {
@@ -267,29 +291,47 @@ impl Colors {
     }
 }
 
-impl std::fmt::Display for Colors {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// Writes the colors in WUBRG order, as Scryfall does, such as `WU` for
+/// [`AZORIUS`][Colors::AZORIUS], or `C` if there are no colors.
+impl fmt::Display for Colors {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if self.is_colorless() {
-            write!(f, "c")
-        } else {
-            let mut s = String::with_capacity(5);
-            if self.is(White) {
-                s.push('w');
-            }
-            if self.is(Blue) {
-                s.push('u');
-            }
-            if self.is(Black) {
-                s.push('b');
-            }
-            if self.is(Red) {
-                s.push('r');
-            }
-            if self.is(Green) {
-                s.push('g');
-            }
-            write!(f, "{}", s)
+            return write!(f, "{}", Colorless);
+        }
+        for color in Color::all().iter().filter(|&&color| self.is(color)) {
+            write!(f, "{}", color)?;
         }
+        Ok(())
+    }
+}
+
+/// Parses colors written as letters in any order and case, such as `wu` or
+/// `UW`. `c` and `colorless` are parsed as no colors. To search for
+/// multicolored cards, use [`Multicolored`] instead.
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card::Colors;
+/// assert_eq!("uw".parse::<Colors>().unwrap(), Colors::AZORIUS);
+/// assert_eq!("uw".parse::<Colors>().unwrap().to_string(), "WU");
+/// assert_eq!("c".parse::<Colors>().unwrap(), Colors::COLORLESS);
+/// assert!("multicolor".parse::<Colors>().is_err());
+/// ```
+impl FromStr for Colors {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("colorless") {
+            return Ok(Colors::colorless());
+        }
+        let invalid = || crate::error::Error::Other(format!("Unknown colors: {:?}", s));
+        if s.is_empty() {
+            return Err(invalid());
+        }
+        s.chars()
+            .map(|c| c.to_string().parse::<Color>().map_err(|_| invalid()))
+            .collect()
     }
 }
 
@@ -305,6 +347,33 @@ impl From<Color> for Colors {
     }
 }
 
+impl FromIterator<Color> for Colors {
+    fn from_iter<I: IntoIterator<Item = Color>>(colors: I) -> Self {
+        colors.into_iter().fold(Colors::colorless(), Colors::with)
+    }
+}
+
+macro_rules! impl_colors_ops {
+    ($($Op:ident::$op:ident => $method:ident,)*) => {
+        $(
+            impl<T: Into<Colors>> $Op<T> for Colors {
+                type Output = Colors;
+
+                fn $op(self, other: T) -> Colors {
+                    self.$method(other.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_colors_ops! {
+    BitOr::bitor => union,
+    BitAnd::bitand => intersection,
+    BitXor::bitxor => symmetric_difference,
+    Sub::sub => difference,
+}
+
 /// Multicolored card. This can be used as a
 /// [`ColorValue`][crate::search::param::value::ColorValue] for searching
 /// Scryfall.
@@ -317,6 +386,21 @@ impl fmt::Display for Multicolored {
     }
 }
 
+/// Parses `m`, `multicolor` or `multicolored`, ignoring case.
+impl FromStr for Multicolored {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "m" | "multicolor" | "multicolored" => Ok(Multicolored),
+            _ => Err(crate::error::Error::Other(format!(
+                "Unknown multicolored value: {:?}",
+                s
+            ))),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -346,6 +430,77 @@ mod tests {
         assert_eq!(Colors::CHAOS.difference(Colors::JESKAI), Colors::GOLGARI);
     }
 
+    #[test]
+    fn subset() {
+        assert!(Colors::COLORLESS.is_subset(Colors::RED));
+        assert!(Colors::AZORIUS.is_subset(Colors::ESPER));
+        assert!(Colors::ESPER.is_subset(Colors::ESPER));
+        assert!(!Colors::ESPER.is_subset(Colors::AZORIUS));
+        assert!(!Colors::GREEN.is_subset(Colors::COLORLESS));
+    }
+
+    #[test]
+    fn operators() {
+        assert_eq!(Colors::RED | Colors::WHITE, Colors::BOROS);
+        assert_eq!(Colors::GOLGARI | White, Colors::ABZAN);
+        assert_eq!(Colors::NAYA & Colors::ESPER, Colors::WHITE);
+        assert_eq!(Colors::SIMIC ^ Blue, Colors::GREEN);
+        assert_eq!(Colors::CHAOS - Colors::JESKAI, Colors::GOLGARI);
+    }
+
+    #[test]
+    fn from_iter() {
+        assert_eq!(
+            Color::all().iter().copied().collect::<Colors>(),
+            Colors::ALL
+        );
+        assert_eq!(
+            vec![Red, Green, Red].into_iter().collect::<Colors>(),
+            Colors::GRUUL
+        );
+        assert_eq!(
+            std::iter::empty::<Color>().collect::<Colors>(),
+            Colors::COLORLESS
+        );
+        assert!(Colors::JUND.contains(Black));
+        assert!(!Colors::JUND.contains(Blue));
+    }
+
+    #[test]
+    fn display_and_parse() {
+        assert_eq!(Colors::AZORIUS.to_string(), "WU");
+        assert_eq!(Colors::ALL.to_string(), "WUBRG");
+        assert_eq!(Colors::COLORLESS.to_string(), "C");
+
+        let matrix = vec![
+            ("uw", Colors::AZORIUS),
+            ("WU", Colors::AZORIUS),
+            ("gRbUw", Colors::ALL),
+            ("rr", Colors::RED),
+            ("c", Colors::COLORLESS),
+            ("Colorless", Colors::COLORLESS),
+        ];
+        for (s, colors) in matrix {
+            assert_eq!(s.parse::<Colors>().unwrap(), colors);
+        }
+        assert_eq!("gw".parse::<Colors>().unwrap().to_string(), "WG");
+        for colors in [
+            Colors::ESPER,
+            Colors::JUND,
+            Colors::GREEN,
+            Colors::COLORLESS,
+        ] {
+            assert_eq!(colors.to_string().parse::<Colors>().unwrap(), colors);
+        }
+        assert!("".parse::<Colors>().is_err());
+        assert!("wx".parse::<Colors>().is_err());
+        assert!("azorius".parse::<Colors>().is_err());
+
+        assert_eq!("multicolor".parse::<Multicolored>().unwrap(), Multicolored);
+        assert_eq!("M".parse::<Multicolored>().unwrap(), Multicolored);
+        assert!("wu".parse::<Multicolored>().is_err());
+    }
+
     #[test]
     fn symmetric_difference() {
         assert_eq!(
@@ -0,0 +1,45 @@
+//! Enum defining the finishes a card can be printed in.
+use crate::util::{from_str_by_display, serde_by_display};
+
+/// The finishes a card printing is available in.
+///
+/// [Official docs](https://scryfall.com/docs/api/cards#print-fields)
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub enum Finish {
+    /// A regular card without foil.
+    Nonfoil,
+    /// A traditional foil card.
+    Foil,
+    /// An etched foil card.
+    Etched,
+    /// A glossy card, used in some promotional printings.
+    Glossy,
+    /// A finish that this version of the crate doesn't know about yet, holding
+    /// the value Scryfall sent.
+    Unknown(String),
+}
+
+impl std::fmt::Display for Finish {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Finish::Nonfoil => "nonfoil",
+                Finish::Foil => "foil",
+                Finish::Etched => "etched",
+                Finish::Glossy => "glossy",
+                Finish::Unknown(s) => s,
+            }
+        )
+    }
+}
+
+from_str_by_display! {
+    Finish, "finish", [
+        Nonfoil, Foil, Etched, Glossy,
+    ]
+}
+
+serde_by_display!(Finish);
@@ -1,28 +1,27 @@
-use serde::{Deserialize, Serialize};
+use crate::util::{from_str_by_display, serde_by_display};
 
 /// The frame field tracks the major edition of the card frame of used for the
 /// re/print in question. The frame has gone though several major revisions in
 /// Magic’s lifetime.
 ///
 /// [Official docs](https://scryfall.com/docs/api/layouts#frames)
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
 pub enum Frame {
     /// The original Magic card frame, starting from Limited Edition Alpha.
-    #[serde(rename = "1993")]
     Y1993,
     /// The updated classic frame starting from Mirage block.
-    #[serde(rename = "1997")]
     Y1997,
     /// The “modern” Magic card frame, introduced in Eighth Edition and Mirrodin
     /// block.
-    #[serde(rename = "2003")]
     Y2003,
     /// The holofoil-stamp Magic card frame, introduced in Magic 2015.
-    #[serde(rename = "2015")]
     Y2015,
     /// The frame used on cards from the future.
-    #[serde(rename = "future")]
     Future,
+    /// A frame that this version of the crate doesn't know about yet, holding
+    /// the value Scryfall sent.
+    Unknown(String),
 }
 
 impl std::fmt::Display for Frame {
@@ -37,7 +36,16 @@ impl std::fmt::Display for Frame {
                 Y2003 => "2003",
                 Y2015 => "2015",
                 Future => "future",
+                Unknown(s) => s,
             }
         )
     }
 }
+
+from_str_by_display! {
+    Frame, "frame", [
+        Y1993, Y1997, Y2003, Y2015, Future,
+    ]
+}
+
+serde_by_display!(Frame);
@@ -1,12 +1,11 @@
-use serde::{Deserialize, Serialize};
+use crate::util::{from_str_by_display, serde_by_display};
 
 /// The frame_effects field tracks additional frame artwork applied over a
 /// particular frame. For example, there are both 2003 and 2015-frame cards with
 /// the Nyx-touched effect.
 ///
 /// [Official docs](https://scryfall.com/docs/api/layouts#frame-effects)
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
-#[serde(rename_all = "lowercase")]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[non_exhaustive]
 pub enum FrameEffect {
     /// The cards have a legendary crown.
@@ -45,6 +44,16 @@ pub enum FrameEffect {
     Etched,
     /// The cards have the snowy frame effect.
     Snow,
+    /// The cards include the Lesson type line.
+    Lesson,
+    /// The cards have the Shattered Glass frame effect.
+    ShatteredGlass,
+    /// The more than meets the eye transform marks.
+    ConvertDfc,
+    /// The fan transform marks.
+    FanDfc,
+    /// The Upside Down transform marks.
+    UpsideDownDfc,
 
     /// A full art frame. Undocumented and unsupported for search.
     FullArt,
@@ -53,6 +62,9 @@ pub enum FrameEffect {
     /// The waxing and waning moon transform marks. Undocumented and unsupported
     /// for search.
     WaxingAndWaningMoonDfc,
+    /// A frame effect that this version of the crate doesn't know about yet, holding
+    /// the value Scryfall sent.
+    Unknown(String),
 }
 
 impl std::fmt::Display for FrameEffect {
@@ -80,11 +92,43 @@ impl std::fmt::Display for FrameEffect {
                 Companion => "companion",
                 Etched => "etched",
                 Snow => "snow",
+                Lesson => "lesson",
+                ShatteredGlass => "shatteredglass",
+                ConvertDfc => "convertdfc",
+                FanDfc => "fandfc",
+                UpsideDownDfc => "upsidedowndfc",
 
                 FullArt => "fullart",
                 Nyxborn => "nyxborn",
                 WaxingAndWaningMoonDfc => "waxingandwaningmoondfc",
+                Unknown(s) => s,
             }
         )
     }
 }
+
+from_str_by_display! {
+    FrameEffect, "frame effect", [
+        Legendary, Miracle, Nyxtouched, Draft, Devoid, Tombstone, Colorshifted, Inverted,
+        SunMoonDfc, CompassLandDfc, OriginPwDfc, MoonEldraziDfc, MoonReverseMoonDfc, Showcase,
+        ExtendedArt, Companion, Etched, Snow, Lesson, ShatteredGlass, ConvertDfc, FanDfc,
+        UpsideDownDfc, FullArt, Nyxborn, WaxingAndWaningMoonDfc,
+    ]
+}
+
+serde_by_display!(FrameEffect);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_matches_serde() {
+        for effect in FrameEffect::VARIANTS {
+            assert_eq!(
+                serde_json::to_string(effect).unwrap(),
+                format!("\"{}\"", effect)
+            );
+        }
+    }
+}
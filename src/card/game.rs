@@ -1,9 +1,8 @@
 //! Enum defining the exiting platforms on with a magic card can exist.
-use serde::{Deserialize, Serialize};
+use crate::util::{from_str_by_display, serde_by_display};
 
 /// Enum defining the exiting platforms on with a magic card can exist.
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[allow(missing_docs)]
 #[non_exhaustive]
 pub enum Game {
@@ -12,6 +11,9 @@ pub enum Game {
     Mtgo,
     Astral,
     Sega,
+    /// A game that this version of the crate doesn't know about yet, holding
+    /// the value Scryfall sent.
+    Unknown(String),
 }
 
 impl std::fmt::Display for Game {
@@ -25,7 +27,16 @@ impl std::fmt::Display for Game {
                 Game::Mtgo => "mtgo",
                 Game::Astral => "astral",
                 Game::Sega => "sega",
+                Game::Unknown(s) => s,
             }
         )
     }
 }
+
+from_str_by_display! {
+    Game, "game", [
+        Paper, Arena, Mtgo, Astral, Sega,
+    ]
+}
+
+serde_by_display!(Game);
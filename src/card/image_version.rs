@@ -0,0 +1,58 @@
+//! Enum describing the versions of a card's image available on Scryfall.
+use serde::{Deserialize, Serialize};
+
+/// The versions of a card's image available on Scryfall. These are the keys of
+/// [`Card::image_uris`][crate::card::Card::image_uris].
+///
+/// For more information, refer to the [official docs](https://scryfall.com/docs/api/images).
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageVersion {
+    /// A small full card image, 146 × 204 JPG.
+    Small,
+    /// A medium-sized full card image, 488 × 680 JPG.
+    Normal,
+    /// A large full card image, 672 × 936 JPG.
+    Large,
+    /// A transparent, rounded full card PNG, 745 × 1040.
+    Png,
+    /// A rectangular crop of the card's art only, of varying size.
+    ArtCrop,
+    /// A full card image with the rounded corners and the majority of the
+    /// border cropped off, 480 × 680 JPG.
+    BorderCrop,
+}
+
+impl std::fmt::Display for ImageVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ImageVersion::Small => "small",
+                ImageVersion::Normal => "normal",
+                ImageVersion::Large => "large",
+                ImageVersion::Png => "png",
+                ImageVersion::ArtCrop => "art_crop",
+                ImageVersion::BorderCrop => "border_crop",
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_matches_serde() {
+        use ImageVersion::*;
+
+        for version in &[Small, Normal, Large, Png, ArtCrop, BorderCrop] {
+            assert_eq!(
+                serde_json::to_value(version).unwrap(),
+                version.to_string().as_str()
+            );
+        }
+    }
+}
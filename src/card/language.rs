@@ -0,0 +1,126 @@
+//! Enum defining the languages a card can be printed in.
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::from_str_by_display;
+
+/// The languages Scryfall has cards in. `Display` and `FromStr` use the
+/// language codes from the [official docs](https://scryfall.com/docs/api/languages).
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card::Language;
+/// assert_eq!("ja".parse::<Language>().unwrap(), Language::Japanese);
+/// assert_eq!(Language::SimplifiedChinese.to_string(), "zhs");
+/// ```
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub enum Language {
+    /// English, `en`.
+    #[serde(rename = "en")]
+    English,
+    /// Spanish, `es`.
+    #[serde(rename = "es")]
+    Spanish,
+    /// French, `fr`.
+    #[serde(rename = "fr")]
+    French,
+    /// German, `de`.
+    #[serde(rename = "de")]
+    German,
+    /// Italian, `it`.
+    #[serde(rename = "it")]
+    Italian,
+    /// Portuguese, `pt`.
+    #[serde(rename = "pt")]
+    Portuguese,
+    /// Japanese, `ja`.
+    #[serde(rename = "ja")]
+    Japanese,
+    /// Korean, `ko`.
+    #[serde(rename = "ko")]
+    Korean,
+    /// Russian, `ru`.
+    #[serde(rename = "ru")]
+    Russian,
+    /// Simplified Chinese, `zhs`.
+    #[serde(rename = "zhs")]
+    SimplifiedChinese,
+    /// Traditional Chinese, `zht`.
+    #[serde(rename = "zht")]
+    TraditionalChinese,
+    /// Hebrew, `he`.
+    #[serde(rename = "he")]
+    Hebrew,
+    /// Latin, `la`.
+    #[serde(rename = "la")]
+    Latin,
+    /// Ancient Greek, `grc`.
+    #[serde(rename = "grc")]
+    AncientGreek,
+    /// Arabic, `ar`.
+    #[serde(rename = "ar")]
+    Arabic,
+    /// Sanskrit, `sa`.
+    #[serde(rename = "sa")]
+    Sanskrit,
+    /// Phyrexian, `ph`.
+    #[serde(rename = "ph")]
+    Phyrexian,
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Language::*;
+        write!(
+            f,
+            "{}",
+            match self {
+                English => "en",
+                Spanish => "es",
+                French => "fr",
+                German => "de",
+                Italian => "it",
+                Portuguese => "pt",
+                Japanese => "ja",
+                Korean => "ko",
+                Russian => "ru",
+                SimplifiedChinese => "zhs",
+                TraditionalChinese => "zht",
+                Hebrew => "he",
+                Latin => "la",
+                AncientGreek => "grc",
+                Arabic => "ar",
+                Sanskrit => "sa",
+                Phyrexian => "ph",
+            }
+        )
+    }
+}
+
+from_str_by_display! {
+    Language, "language", [
+        English, Spanish, French, German, Italian, Portuguese, Japanese, Korean,
+        Russian, SimplifiedChinese, TraditionalChinese, Hebrew, Latin,
+        AncientGreek, Arabic, Sanskrit, Phyrexian,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn language_codes_round_trip() {
+        for language in Language::VARIANTS {
+            let code = language.to_string();
+            assert_eq!(code.parse::<Language>().unwrap(), *language);
+            assert_eq!(
+                serde_json::from_str::<Language>(&format!("\"{}\"", code)).unwrap(),
+                *language
+            );
+        }
+        assert!("xx".parse::<Language>().is_err());
+    }
+}
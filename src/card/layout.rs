@@ -1,4 +1,4 @@
-use serde::{Deserialize, Serialize};
+use crate::util::{from_str_by_display, serde_by_display};
 
 /// The layout property categorizes the arrangement of card parts, faces, and
 /// other bounded regions on cards. The layout can be used to programmatically
@@ -13,13 +13,12 @@ use serde::{Deserialize, Serialize};
 ///   pointing to the other meld parts.
 ///
 /// [Official docs](https://scryfall.com/docs/api/layouts#layout)
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[non_exhaustive]
 pub enum Layout {
     /// A standard Magic card with one face.
     Normal,
-    /// A split-faced card.
+    /// A split-faced card. Cards with aftermath also use this layout.
     Split,
     /// Cards that invert vertically with the flip keyword.
     Flip,
@@ -31,6 +30,8 @@ pub enum Layout {
     Meld,
     /// Cards with Level Up.
     Leveler,
+    /// Class-type enchantment cards.
+    Class,
     /// Saga-type cards.
     Saga,
     /// Cards with an Adventure spell part.
@@ -55,4 +56,117 @@ pub enum Layout {
     ArtSeries,
     /// A Magic card with two sides that are unrelated.
     DoubleSided,
+    /// A single card printed on both sides, such as the reversible cards from
+    /// Secret Lair.
+    ReversibleCard,
+    /// A layout that this version of the crate doesn't know about yet, holding
+    /// the value Scryfall sent.
+    Unknown(String),
+}
+
+impl std::fmt::Display for Layout {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        use Layout::*;
+        write!(
+            f,
+            "{}",
+            match self {
+                Normal => "normal",
+                Split => "split",
+                Flip => "flip",
+                Transform => "transform",
+                ModalDfc => "modal_dfc",
+                Meld => "meld",
+                Leveler => "leveler",
+                Class => "class",
+                Saga => "saga",
+                Adventure => "adventure",
+                Planar => "planar",
+                Scheme => "scheme",
+                Vanguard => "vanguard",
+                Token => "token",
+                DoubleFacedToken => "double_faced_token",
+                Emblem => "emblem",
+                Augment => "augment",
+                Host => "host",
+                ArtSeries => "art_series",
+                DoubleSided => "double_sided",
+                ReversibleCard => "reversible_card",
+                Unknown(s) => s,
+            }
+        )
+    }
+}
+
+from_str_by_display! {
+    Layout, "layout", [
+        Normal, Split, Flip, Transform, ModalDfc, Meld, Leveler, Class, Saga, Adventure, Planar,
+        Scheme, Vanguard, Token, DoubleFacedToken, Emblem, Augment, Host, ArtSeries, DoubleSided,
+        ReversibleCard,
+    ]
+}
+
+serde_by_display!(Layout);
+
+impl Layout {
+    /// Checks if cards with this layout are printed on both sides, such as
+    /// transforming and modal double-faced cards. Meld cards are included,
+    /// although Scryfall doesn't report their back faces as card faces.
+    pub fn is_double_faced(&self) -> bool {
+        matches!(
+            self,
+            Layout::Transform
+                | Layout::ModalDfc
+                | Layout::Meld
+                | Layout::DoubleFacedToken
+                | Layout::ArtSeries
+                | Layout::DoubleSided
+                | Layout::ReversibleCard
+        )
+    }
+
+    /// Checks if cards with this layout have more than one face, which
+    /// Scryfall reports in [`card_faces`][crate::Card::card_faces]. This
+    /// includes split, flip and adventure cards, which only have one physical
+    /// side, but not meld cards.
+    pub fn has_multiple_faces(&self) -> bool {
+        matches!(
+            self,
+            Layout::Split
+                | Layout::Flip
+                | Layout::Transform
+                | Layout::ModalDfc
+                | Layout::Adventure
+                | Layout::DoubleFacedToken
+                | Layout::ArtSeries
+                | Layout::DoubleSided
+                | Layout::ReversibleCard
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::from_str;
+
+    use super::*;
+
+    #[test]
+    fn faces() {
+        let matrix = vec![
+            (r#""normal""#, Layout::Normal, false, false),
+            (r#""split""#, Layout::Split, false, true),
+            (r#""class""#, Layout::Class, false, false),
+            (r#""adventure""#, Layout::Adventure, false, true),
+            (r#""meld""#, Layout::Meld, true, false),
+            (r#""modal_dfc""#, Layout::ModalDfc, true, true),
+            (r#""reversible_card""#, Layout::ReversibleCard, true, true),
+        ];
+
+        for (json, layout, double_faced, multiple_faces) in matrix {
+            assert_eq!(from_str::<Layout>(json).unwrap(), layout);
+            assert_eq!(layout.is_double_faced(), double_faced, "{:?}", layout);
+            assert_eq!(layout.has_multiple_faces(), multiple_faces, "{:?}", layout);
+        }
+    }
 }
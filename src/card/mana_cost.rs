@@ -0,0 +1,308 @@
+//! Module defining a parsed mana cost and the symbols it is made of.
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::card::{Color, Colors};
+use crate::error::Error;
+
+/// A single mana symbol, as found in a card's mana cost.
+///
+/// Symbols only found on funny cards, such as `{½}` or `{∞}`, are not
+/// supported.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ManaSymbol {
+    /// A generic mana symbol, such as `{2}`.
+    Generic(u32),
+    /// One mana of a specific color, such as `{W}`, or colorless mana, `{C}`.
+    Colored(Color),
+    /// A hybrid mana symbol that can be paid with either color, such as
+    /// `{W/U}`.
+    Hybrid(Color, Color),
+    /// A hybrid mana symbol that can be paid with one mana of a color or two
+    /// generic mana, such as `{2/W}`.
+    TwoHybrid(Color),
+    /// A Phyrexian mana symbol that can be paid with a color or 2 life, such as
+    /// `{W/P}`.
+    Phyrexian(Color),
+    /// A hybrid Phyrexian mana symbol that can be paid with either color or 2
+    /// life, such as `{G/W/P}`.
+    PhyrexianHybrid(Color, Color),
+    /// A snow mana symbol, `{S}`.
+    Snow,
+    /// The variable mana symbol `{X}`.
+    X,
+    /// The variable mana symbol `{Y}`.
+    Y,
+    /// The variable mana symbol `{Z}`.
+    Z,
+}
+
+impl ManaSymbol {
+    /// The converted mana cost of this symbol. Variable symbols count as 0.
+    pub fn cmc(self) -> u32 {
+        match self {
+            ManaSymbol::Generic(n) => n,
+            ManaSymbol::TwoHybrid(_) => 2,
+            ManaSymbol::X | ManaSymbol::Y | ManaSymbol::Z => 0,
+            _ => 1,
+        }
+    }
+
+    /// The colors of this symbol.
+    pub fn colors(self) -> Colors {
+        match self {
+            ManaSymbol::Colored(color)
+            | ManaSymbol::TwoHybrid(color)
+            | ManaSymbol::Phyrexian(color) => color.into(),
+            ManaSymbol::Hybrid(a, b) | ManaSymbol::PhyrexianHybrid(a, b) => {
+                Colors::monocolor(a).with(b)
+            },
+            _ => Colors::colorless(),
+        }
+    }
+
+    /// Checks if this is a hybrid mana symbol.
+    pub fn is_hybrid(self) -> bool {
+        matches!(
+            self,
+            ManaSymbol::Hybrid(..) | ManaSymbol::TwoHybrid(_) | ManaSymbol::PhyrexianHybrid(..)
+        )
+    }
+}
+
+impl fmt::Display for ManaSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ManaSymbol::Generic(n) => write!(f, "{{{}}}", n),
+            ManaSymbol::Colored(color) => write!(f, "{{{}}}", color),
+            ManaSymbol::Hybrid(a, b) => write!(f, "{{{}/{}}}", a, b),
+            ManaSymbol::TwoHybrid(color) => write!(f, "{{2/{}}}", color),
+            ManaSymbol::Phyrexian(color) => write!(f, "{{{}/P}}", color),
+            ManaSymbol::PhyrexianHybrid(a, b) => write!(f, "{{{}/{}/P}}", a, b),
+            ManaSymbol::Snow => write!(f, "{{S}}"),
+            ManaSymbol::X => write!(f, "{{X}}"),
+            ManaSymbol::Y => write!(f, "{{Y}}"),
+            ManaSymbol::Z => write!(f, "{{Z}}"),
+        }
+    }
+}
+
+impl FromStr for ManaSymbol {
+    type Err = Error;
+
+    /// Parses a single symbol, with or without the surrounding braces.
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let inner = s.strip_prefix('{').unwrap_or(s);
+        let inner = inner.strip_suffix('}').unwrap_or(inner);
+        let invalid = || Error::Other(format!("Invalid mana symbol: {}", s));
+
+        fn color(part: &str) -> Option<Color> {
+            match part {
+                "W" => Some(Color::White),
+                "U" => Some(Color::Blue),
+                "B" => Some(Color::Black),
+                "R" => Some(Color::Red),
+                "G" => Some(Color::Green),
+                _ => None,
+            }
+        }
+
+        let inner = inner.to_ascii_uppercase();
+        let parts = inner.split('/').collect::<Vec<_>>();
+        let symbol = match parts.as_slice() {
+            ["C"] => ManaSymbol::Colored(Color::Colorless),
+            ["S"] => ManaSymbol::Snow,
+            ["X"] => ManaSymbol::X,
+            ["Y"] => ManaSymbol::Y,
+            ["Z"] => ManaSymbol::Z,
+            [n] if !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()) => {
+                ManaSymbol::Generic(n.parse().map_err(|_| invalid())?)
+            },
+            [c] => ManaSymbol::Colored(color(c).ok_or_else(invalid)?),
+            ["2", c] => ManaSymbol::TwoHybrid(color(c).ok_or_else(invalid)?),
+            [c, "P"] => ManaSymbol::Phyrexian(color(c).ok_or_else(invalid)?),
+            [a, b] => {
+                ManaSymbol::Hybrid(color(a).ok_or_else(invalid)?, color(b).ok_or_else(invalid)?)
+            },
+            [a, b, "P"] => ManaSymbol::PhyrexianHybrid(
+                color(a).ok_or_else(invalid)?,
+                color(b).ok_or_else(invalid)?,
+            ),
+            _ => return Err(invalid()),
+        };
+        Ok(symbol)
+    }
+}
+
+/// A mana cost, parsed into its individual [`ManaSymbol`]s.
+///
+/// The `Display` impl writes the cost back in the same notation Scryfall
+/// uses, such as `{2}{W}{U}`.
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card::{Colors, ManaCost, ManaSymbol};
+/// let cost = ManaCost::parse("{2}{W}{U}").unwrap();
+/// assert_eq!(cost.cmc(), 4.0);
+/// assert_eq!(cost.colors(), Colors::AZORIUS);
+/// assert_eq!(cost.symbols()[0], ManaSymbol::Generic(2));
+/// assert_eq!(cost.to_string(), "{2}{W}{U}");
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct ManaCost(Vec<ManaSymbol>);
+
+impl ManaCost {
+    /// Parses a mana cost written as a sequence of symbols in curly braces,
+    /// such as `{2}{W}{U}`. An empty string is parsed as an empty cost.
+    pub fn parse(cost: &str) -> crate::Result<Self> {
+        let invalid = || Error::Other(format!("Invalid mana cost: {}", cost));
+        let mut symbols = Vec::new();
+        let mut rest = cost.trim();
+        while !rest.is_empty() {
+            if !rest.starts_with('{') {
+                return Err(invalid());
+            }
+            let end = rest.find('}').ok_or_else(invalid)?;
+            symbols.push(rest[..=end].parse()?);
+            rest = &rest[end + 1..];
+        }
+        Ok(ManaCost(symbols))
+    }
+
+    /// The symbols in this mana cost, in order.
+    pub fn symbols(&self) -> &[ManaSymbol] {
+        &self.0
+    }
+
+    /// The converted mana cost of this mana cost.
+    pub fn cmc(&self) -> f32 {
+        self.0.iter().map(|s| s.cmc()).sum::<u32>() as f32
+    }
+
+    /// The colors of all the symbols in this mana cost.
+    pub fn colors(&self) -> Colors {
+        self.0
+            .iter()
+            .fold(Colors::colorless(), |colors, s| colors.union(s.colors()))
+    }
+
+    /// Checks if this mana cost contains any hybrid mana symbols.
+    pub fn contains_hybrid(&self) -> bool {
+        self.0.iter().any(|s| s.is_hybrid())
+    }
+
+    /// Counts the colored mana symbols in this mana cost, by color. This is
+    /// how much the cost adds to its controller's devotion to each color.
+    ///
+    /// Hybrid symbols count toward both of their colors, and Phyrexian symbols
+    /// count toward their color. Generic, colorless and snow symbols aren't
+    /// counted, and colors without symbols are left out.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Color, ManaCost};
+    /// let pips = ManaCost::parse("{1}{W/U}{W}").unwrap().color_pips();
+    /// assert_eq!(pips[&Color::White], 2);
+    /// assert_eq!(pips[&Color::Blue], 1);
+    /// assert_eq!(pips.len(), 2);
+    /// ```
+    pub fn color_pips(&self) -> HashMap<Color, usize> {
+        let mut pips = HashMap::new();
+        for symbol in &self.0 {
+            let colors = symbol.colors();
+            for &color in Color::all().iter().filter(|&&c| colors.contains(c)) {
+                *pips.entry(color).or_insert(0) += 1;
+            }
+        }
+        pips
+    }
+}
+
+impl fmt::Display for ManaCost {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for symbol in &self.0 {
+            write!(f, "{}", symbol)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ManaCost {
+    type Err = Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        ManaCost::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_symbols() {
+        use Color::*;
+
+        let matrix = vec![
+            ("{0}", ManaSymbol::Generic(0)),
+            ("{15}", ManaSymbol::Generic(15)),
+            ("{W}", ManaSymbol::Colored(White)),
+            ("{C}", ManaSymbol::Colored(Colorless)),
+            ("{W/U}", ManaSymbol::Hybrid(White, Blue)),
+            ("{2/B}", ManaSymbol::TwoHybrid(Black)),
+            ("{R/P}", ManaSymbol::Phyrexian(Red)),
+            ("{G/W/P}", ManaSymbol::PhyrexianHybrid(Green, White)),
+            ("{S}", ManaSymbol::Snow),
+            ("{X}", ManaSymbol::X),
+            ("{Y}", ManaSymbol::Y),
+            ("{Z}", ManaSymbol::Z),
+        ];
+
+        for (s, symbol) in matrix {
+            assert_eq!(s.parse::<ManaSymbol>().unwrap(), symbol);
+            assert_eq!(symbol.to_string(), s);
+        }
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for s in &["{Q}", "{W/Q}", "{}", "W", "{W", "{2}{T}", "{W/U/B/P}"] {
+            assert!(ManaCost::parse(s).is_err(), "{} should not parse", s);
+        }
+    }
+
+    #[test]
+    fn mana_cost_helpers() {
+        let cost = ManaCost::parse("{X}{2}{W/U}{G/P}").unwrap();
+        assert_eq!(cost.symbols().len(), 4);
+        assert_eq!(cost.cmc(), 4.0);
+        assert_eq!(cost.colors(), Colors::BANT);
+        assert!(cost.contains_hybrid());
+        assert_eq!(cost.to_string(), "{X}{2}{W/U}{G/P}");
+
+        let empty = ManaCost::parse("").unwrap();
+        assert_eq!(empty.cmc(), 0.0);
+        assert!(empty.colors().is_colorless());
+        assert!(!empty.contains_hybrid());
+    }
+
+    #[test]
+    fn color_pips() {
+        use Color::*;
+
+        let pips = |cost| ManaCost::parse(cost).unwrap().color_pips();
+        assert_eq!(
+            pips("{W/U}{W}"),
+            vec![(White, 2), (Blue, 1)].into_iter().collect()
+        );
+        assert_eq!(
+            pips("{2/B}{R/P}{G/W/P}{B}"),
+            vec![(Black, 2), (Red, 1), (Green, 1), (White, 1)]
+                .into_iter()
+                .collect()
+        );
+        assert!(pips("{X}{3}{C}{S}").is_empty());
+        assert!(pips("").is_empty());
+    }
+}
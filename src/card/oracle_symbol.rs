@@ -0,0 +1,159 @@
+//! Module defining the symbols found in a card's Oracle text.
+use std::fmt;
+use std::str::FromStr;
+
+use crate::card::ManaSymbol;
+use crate::error::Error;
+
+/// A symbol found in a card's Oracle text, such as the `{T}` and `{W}` in
+/// "{T}: Add {W}.".
+///
+/// The `Display` impl writes the symbol back in the same notation Scryfall
+/// uses, with the surrounding braces.
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card::{Color, ManaSymbol, OracleSymbol};
+/// assert_eq!("{T}".parse::<OracleSymbol>().unwrap(), OracleSymbol::Tap);
+/// assert_eq!(
+///     "{W}".parse::<OracleSymbol>().unwrap(),
+///     OracleSymbol::Mana(ManaSymbol::Colored(Color::White))
+/// );
+/// assert_eq!(
+///     "{CHAOS}".parse::<OracleSymbol>().unwrap(),
+///     OracleSymbol::Other("CHAOS".to_string())
+/// );
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum OracleSymbol {
+    /// A mana symbol, such as `{W}` or `{2/B}`.
+    Mana(ManaSymbol),
+    /// The tap symbol, `{T}`.
+    Tap,
+    /// The untap symbol, `{Q}`.
+    Untap,
+    /// The energy counter symbol, `{E}`.
+    Energy,
+    /// Any other symbol, such as `{CHAOS}` or `{PW}`, holding the text between
+    /// the braces.
+    Other(String),
+}
+
+impl fmt::Display for OracleSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OracleSymbol::Mana(symbol) => write!(f, "{}", symbol),
+            OracleSymbol::Tap => write!(f, "{{T}}"),
+            OracleSymbol::Untap => write!(f, "{{Q}}"),
+            OracleSymbol::Energy => write!(f, "{{E}}"),
+            OracleSymbol::Other(symbol) => write!(f, "{{{}}}", symbol),
+        }
+    }
+}
+
+impl FromStr for OracleSymbol {
+    type Err = Error;
+
+    /// Parses a single symbol, with or without the surrounding braces.
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let inner = s.strip_prefix('{').unwrap_or(s);
+        let inner = inner.strip_suffix('}').unwrap_or(inner);
+        if inner.is_empty() || inner.contains(&['{', '}'][..]) {
+            return Err(Error::Other(format!("Invalid symbol: {}", s)));
+        }
+        Ok(match inner {
+            "T" => OracleSymbol::Tap,
+            "Q" => OracleSymbol::Untap,
+            "E" => OracleSymbol::Energy,
+            _ => match inner.parse() {
+                Ok(symbol) => OracleSymbol::Mana(symbol),
+                Err(_) => OracleSymbol::Other(inner.to_string()),
+            },
+        })
+    }
+}
+
+/// Calls `f` with each symbol in `text`, in order, and replaces the symbol
+/// with the string it returns. The rest of the text, including braces that
+/// don't surround a symbol, is copied unchanged.
+pub(crate) fn replace_symbols(text: &str, mut f: impl FnMut(OracleSymbol) -> String) -> String {
+    let mut rendered = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        rest = &rest[start..];
+        match rest[1..].find(&['{', '}'][..]).map(|i| i + 1) {
+            Some(end) if rest.as_bytes()[end] == b'}' => {
+                let symbol = &rest[..=end];
+                match symbol.parse() {
+                    Ok(symbol) => rendered.push_str(&f(symbol)),
+                    Err(_) => rendered.push_str(symbol),
+                }
+                rest = &rest[end + 1..];
+            },
+            // Another symbol starts before this one ends.
+            Some(next) => {
+                rendered.push_str(&rest[..next]);
+                rest = &rest[next..];
+            },
+            None => break,
+        }
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::Color;
+
+    #[test]
+    fn parse_symbols() {
+        let matrix = vec![
+            ("{T}", OracleSymbol::Tap),
+            ("{Q}", OracleSymbol::Untap),
+            ("{E}", OracleSymbol::Energy),
+            (
+                "{W/U}",
+                OracleSymbol::Mana(ManaSymbol::Hybrid(Color::White, Color::Blue)),
+            ),
+            ("{CHAOS}", OracleSymbol::Other("CHAOS".to_string())),
+        ];
+        for (s, symbol) in matrix {
+            assert_eq!(s.parse::<OracleSymbol>().unwrap(), symbol);
+            assert_eq!(symbol.to_string(), s);
+        }
+        assert!("{}".parse::<OracleSymbol>().is_err());
+    }
+
+    #[test]
+    fn replace_text_symbols() {
+        let text = "{T}, Pay {E}{E}: Add {W/U}. {2}{Q}: Roll {CHAOS} {a card.";
+        let mut symbols = Vec::new();
+        let rendered = replace_symbols(text, |symbol| {
+            let rendered = format!("<{}>", symbol.to_string().trim_matches(&['{', '}'][..]));
+            symbols.push(symbol);
+            rendered
+        });
+        assert_eq!(
+            symbols,
+            [
+                OracleSymbol::Tap,
+                OracleSymbol::Energy,
+                OracleSymbol::Energy,
+                OracleSymbol::Mana(ManaSymbol::Hybrid(Color::White, Color::Blue)),
+                OracleSymbol::Mana(ManaSymbol::Generic(2)),
+                OracleSymbol::Untap,
+                OracleSymbol::Other("CHAOS".to_string()),
+            ]
+        );
+        assert_eq!(
+            rendered,
+            "<T>, Pay <E><E>: Add <W/U>. <2><Q>: Roll <CHAOS> {a card."
+        );
+        assert_eq!(replace_symbols("{{R}}", |_| "red".to_string()), "{red}");
+        assert_eq!(replace_symbols("{}", |_| unreachable!()), "{}");
+        assert_eq!(replace_symbols("", |_| unreachable!()), "");
+    }
+}
@@ -0,0 +1,107 @@
+//! Module defining a parsed power or toughness value.
+use std::fmt;
+
+/// A card's power or toughness, parsed from the string Scryfall reports.
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card::PowTou;
+/// assert_eq!(PowTou::parse("3"), PowTou::Numeric(3));
+/// assert_eq!(PowTou::parse("*"), PowTou::Star);
+/// assert_eq!(PowTou::parse("1+*"), PowTou::StarPlus(1));
+/// assert_eq!(PowTou::parse("X"), PowTou::Variable("X".to_string()));
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PowTou {
+    /// A plain number, such as `3` or `-1`.
+    Numeric(i32),
+    /// Any other value, such as `X`, `∞` or `?`.
+    Variable(String),
+    /// A value defined by the card's abilities, `*`.
+    Star,
+    /// A value defined by the card's abilities plus a number, such as `1+*`.
+    StarPlus(i32),
+}
+
+impl PowTou {
+    /// Parses a power or toughness value. Values that aren't a number or a
+    /// star are kept as [`PowTou::Variable`].
+    pub fn parse(value: &str) -> Self {
+        let value = value.trim();
+        let number = |s: &str| {
+            if s.starts_with('+') {
+                None
+            } else {
+                s.parse::<i32>().ok()
+            }
+        };
+        if value == "*" {
+            return PowTou::Star;
+        }
+        if let Some(n) = number(value) {
+            return PowTou::Numeric(n);
+        }
+        let plus = value
+            .strip_suffix("+*")
+            .or_else(|| value.strip_prefix("*+"))
+            .and_then(number);
+        match plus {
+            Some(n) => PowTou::StarPlus(n),
+            None => PowTou::Variable(value.to_string()),
+        }
+    }
+
+    /// The numeric value, if this is [`PowTou::Numeric`].
+    pub fn as_number(&self) -> Option<i32> {
+        match self {
+            PowTou::Numeric(n) => Some(*n),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for PowTou {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PowTou::Numeric(n) => write!(f, "{}", n),
+            PowTou::Variable(s) => write!(f, "{}", s),
+            PowTou::Star => write!(f, "*"),
+            PowTou::StarPlus(n) => write!(f, "{}+*", n),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_pow_tou() {
+        let matrix = vec![
+            ("0", PowTou::Numeric(0)),
+            ("13", PowTou::Numeric(13)),
+            ("-1", PowTou::Numeric(-1)),
+            ("*", PowTou::Star),
+            ("1+*", PowTou::StarPlus(1)),
+            ("*+1", PowTou::StarPlus(1)),
+            ("X", PowTou::Variable("X".to_string())),
+            ("+1", PowTou::Variable("+1".to_string())),
+            ("?", PowTou::Variable("?".to_string())),
+            ("*²", PowTou::Variable("*²".to_string())),
+        ];
+
+        for (s, pow_tou) in matrix {
+            assert_eq!(PowTou::parse(s), pow_tou, "{}", s);
+        }
+    }
+
+    #[test]
+    fn infinity_elemental() {
+        // Infinity Elemental is an ∞/5.
+        let power = PowTou::parse("∞");
+        assert_eq!(power, PowTou::Variable("∞".to_string()));
+        assert_eq!(power.as_number(), None);
+        assert_eq!(power.to_string(), "∞");
+        assert_eq!(PowTou::parse("5").as_number(), Some(5));
+    }
+}
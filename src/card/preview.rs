@@ -14,7 +14,7 @@ pub struct Preview {
     /// NOTE: Sometimes this is an empty string, causing the `Url`
     /// deserialization to fail. If this happens, a `None` variant is used
     /// instead.
-    #[serde(deserialize_with = "crate::util::deserialize_or_none")]
+    #[serde(default, deserialize_with = "crate::util::deserialize_or_none")]
     pub source_uri: Option<Url>,
 
     /// The name of the source that previewed this card.
@@ -3,6 +3,8 @@ use std::cmp::Ordering;
 
 use serde::{Deserialize, Serialize};
 
+use crate::search::param::value::Currency;
+
 /// Struct defining a price object containing data in various currencies.
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug, Default)]
 #[allow(missing_docs)]
@@ -15,6 +17,68 @@ pub struct Price {
 }
 
 impl Price {
+    /// The nonfoil price in US dollars, as a number.
+    pub fn usd_value(&self) -> Option<f64> {
+        parse_price(&self.usd)
+    }
+
+    /// The foil price in US dollars, as a number.
+    pub fn usd_foil_value(&self) -> Option<f64> {
+        parse_price(&self.usd_foil)
+    }
+
+    /// The nonfoil price in Euros, as a number.
+    pub fn eur_value(&self) -> Option<f64> {
+        parse_price(&self.eur)
+    }
+
+    /// The foil price in Euros, as a number.
+    pub fn eur_foil_value(&self) -> Option<f64> {
+        parse_price(&self.eur_foil)
+    }
+
+    /// The price in MTGO tickets, as a number.
+    pub fn tix_value(&self) -> Option<f64> {
+        parse_price(&self.tix)
+    }
+
+    /// The price in `currency`, as a number.
+    pub fn value_in(&self, currency: Currency) -> Option<f64> {
+        match currency {
+            Currency::Usd => self.usd_value(),
+            Currency::UsdFoil => self.usd_foil_value(),
+            Currency::Eur => self.eur_value(),
+            Currency::Tix => self.tix_value(),
+        }
+    }
+
+    /// The lowest nonfoil price in US dollars among `prices`, such as the
+    /// prices of every printing of a card. Prices that are missing are
+    /// skipped.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Price;
+    /// let prices = vec![
+    ///     Price {
+    ///         usd: Some("1999.99".to_string()),
+    ///         ..Default::default()
+    ///     },
+    ///     Price {
+    ///         usd: Some("0.25".to_string()),
+    ///         ..Default::default()
+    ///     },
+    ///     Price::default(),
+    /// ];
+    /// assert_eq!(Price::cheapest_nonfoil(&prices), Some(0.25));
+    /// ```
+    pub fn cheapest_nonfoil<'a>(prices: impl IntoIterator<Item = &'a Price>) -> Option<f64> {
+        prices
+            .into_iter()
+            .filter_map(Price::usd_value)
+            .reduce(f64::min)
+    }
+
     /// Creates an array of component prices that can be iterated over.
     fn to_array(&self) -> [&Option<String>; 5] {
         [
@@ -27,6 +91,11 @@ impl Price {
     }
 }
 
+/// Parses a price, treating missing, empty and malformed prices as `None`.
+fn parse_price(price: &Option<String>) -> Option<f64> {
+    price.as_deref()?.trim().parse().ok()
+}
+
 /// Compares two prices as floating-point numbers.
 fn compare_prices(a: &Option<String>, b: &Option<String>) -> Option<Ordering> {
     if let (Some(a), Some(b)) = (a, b) {
@@ -41,7 +110,7 @@ impl PartialOrd for Price {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         let mut result = None;
         for (a, b) in self.to_array().iter().zip(other.to_array().iter()) {
-            match (result, compare_prices(*a, *b)) {
+            match (result, compare_prices(a, b)) {
                 // If either ordering is `None`, use the other. Then if either is `Some(Equal)`,
                 // use the other.
                 (None, order)
@@ -106,6 +175,41 @@ mod tests {
         assert_eq!(a.partial_cmp(&b), None);
     }
 
+    #[test]
+    fn price_values() {
+        let price = Price {
+            usd: Some("0.25".to_string()),
+            usd_foil: Some("1999.99".to_string()),
+            eur: Some("".to_string()),
+            eur_foil: Some("n/a".to_string()),
+            tix: None,
+        };
+
+        assert_eq!(price.usd_value(), Some(0.25));
+        assert_eq!(price.usd_foil_value(), Some(1999.99));
+        assert_eq!(price.eur_value(), None);
+        assert_eq!(price.eur_foil_value(), None);
+        assert_eq!(price.tix_value(), None);
+        assert_eq!(price.value_in(Currency::Usd), Some(0.25));
+        assert_eq!(price.value_in(Currency::UsdFoil), Some(1999.99));
+        assert_eq!(price.value_in(Currency::Eur), None);
+        assert_eq!(price.value_in(Currency::Tix), None);
+    }
+
+    #[test]
+    fn cheapest_nonfoil() {
+        let price = |usd: Option<&str>| Price {
+            usd: usd.map(str::to_string),
+            usd_foil: Some("0.01".to_string()),
+            ..Default::default()
+        };
+        let prices = vec![price(Some("3.50")), price(None), price(Some("0.49"))];
+
+        assert_eq!(Price::cheapest_nonfoil(&prices), Some(0.49));
+        assert_eq!(Price::cheapest_nonfoil(&[price(None)]), None);
+        assert_eq!(Price::cheapest_nonfoil(&[]), None);
+    }
+
     #[test]
     fn prices_equal() {
         let a = Price {
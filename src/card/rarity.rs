@@ -1,7 +1,7 @@
 //! Enum defining the 4 different rarities a card can come in.
 use std::fmt;
 
-use serde::{Deserialize, Serialize};
+use crate::util::{from_str_by_display, serde_by_display};
 
 /// The rarities a card can be printed in. Aside from the usual 4 of
 /// `Common`, `Uncommon`, `Rare`, and `Mythic`, there are two additional
@@ -12,8 +12,9 @@ use serde::{Deserialize, Serialize};
 ///
 /// For the purposes of sorting and comparison, `Special` is considered above
 /// `Rare` and below `Mythic`, and `Bonus` is the rarest, above `Mythic.
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
-#[serde(rename_all = "snake_case")]
+/// `Unknown` sorts above all of them.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
 pub enum Rarity {
     /// Black set symbol.
     Common,
@@ -27,6 +28,9 @@ pub enum Rarity {
     Mythic,
     /// "Glowing" mythic symbol, used for the power nine in VMA.
     Bonus,
+    /// A rarity that this version of the crate doesn't know about yet, holding
+    /// the value Scryfall sent.
+    Unknown(String),
 }
 
 impl fmt::Display for Rarity {
@@ -41,7 +45,46 @@ impl fmt::Display for Rarity {
                 Rarity::Special => "special",
                 Rarity::Mythic => "mythic",
                 Rarity::Bonus => "bonus",
+                Rarity::Unknown(s) => s,
             }
         )
     }
 }
+
+from_str_by_display! {
+    Rarity, "rarity", [
+        Common, Uncommon, Rare, Special, Mythic, Bonus,
+    ]
+}
+
+serde_by_display!(Rarity);
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_str, to_string};
+
+    use super::*;
+
+    #[test]
+    fn deserialize_unknown_rarity() {
+        assert_eq!(from_str::<Rarity>(r#""mythic""#).unwrap(), Rarity::Mythic);
+        let legendary = Rarity::Unknown("legendary".to_string());
+        assert_eq!(from_str::<Rarity>(r#""legendary""#).unwrap(), legendary);
+        assert!(legendary > Rarity::Bonus);
+    }
+
+    #[test]
+    fn unknown_rarity_round_trips() {
+        let legendary = Rarity::Unknown("legendary".to_string());
+        assert_eq!(legendary.to_string(), "legendary");
+        let json = to_string(&legendary).unwrap();
+        assert_eq!(json, r#""legendary""#);
+        assert_eq!(from_str::<Rarity>(&json).unwrap(), legendary);
+
+        for rarity in Rarity::VARIANTS {
+            let json = to_string(rarity).unwrap();
+            assert_eq!(json, format!("\"{}\"", rarity));
+            assert_eq!(&from_str::<Rarity>(&json).unwrap(), rarity);
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
 use crate::card::Card;
 use crate::uri::Uri;
+use crate::util::{from_str_by_display, serde_by_display, Uuid};
 
 /// Cards that are closely related to other cards (because they call them by
 /// name, or generate a token, or meld, etc) have a `all_parts` property that
@@ -14,26 +14,127 @@ pub struct RelatedCard {
     /// An unique ID for this card in Scryfall’s database.
     pub id: Uuid,
 
-    /// A content type for this object, always related_card.
+    /// A field explaining what role this card plays in this relationship.
     pub component: Component,
 
-    /// A field explaining what role this card plays in this relationship.
+    /// The name of this particular related card.
     pub name: String,
 
-    /// The name of this particular related card.
+    /// The type line of this particular related card.
     pub type_line: String,
 
-    /// The name of this particular related card.
+    /// A URI where you can retrieve a full object describing this card on
+    /// Scryfall’s API.
     pub uri: Uri<Card>,
 }
 
+impl RelatedCard {
+    /// The role this card plays in the relationship.
+    pub fn component(&self) -> &Component {
+        &self.component
+    }
+
+    /// Fetches the full card object for this related card, following its
+    /// `uri`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, Component};
+    /// let bruna = Card::named("Bruna, the Fading Light").unwrap();
+    /// let brisela = bruna
+    ///     .all_parts
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .find(|part| part.component == Component::MeldResult)
+    ///     .unwrap()
+    ///     .fetch()
+    ///     .unwrap();
+    /// assert_eq!(brisela.name, "Brisela, Voice of Nightmares");
+    /// ```
+    pub fn fetch(&self) -> crate::Result<Card> {
+        self.uri.fetch()
+    }
+}
+
 /// The kind of related card.
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[non_exhaustive]
 pub enum Component {
+    /// A token created by the card.
     Token,
+    /// One of the cards that melds into a meld result.
     MeldPart,
+    /// The card produced by melding the meld parts.
     MeldResult,
+    /// A card that is part of a combo with this card, or refers to it by
+    /// name.
     ComboPiece,
+    /// A component that this version of the crate doesn't know about yet,
+    /// holding the value Scryfall sent.
+    Unknown(String),
+}
+
+impl std::fmt::Display for Component {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Component::Token => "token",
+                Component::MeldPart => "meld_part",
+                Component::MeldResult => "meld_result",
+                Component::ComboPiece => "combo_piece",
+                Component::Unknown(s) => s,
+            }
+        )
+    }
+}
+
+from_str_by_display! {
+    Component, "component", [
+        Token, MeldPart, MeldResult, ComboPiece,
+    ]
+}
+
+serde_by_display!(Component);
+
+#[cfg(test)]
+mod tests {
+    use serde_json::from_str;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_components() {
+        let matrix = vec![
+            (r#""token""#, Component::Token),
+            (r#""meld_part""#, Component::MeldPart),
+            (r#""meld_result""#, Component::MeldResult),
+            (r#""combo_piece""#, Component::ComboPiece),
+            (
+                r#""some_new_component""#,
+                Component::Unknown("some_new_component".to_string()),
+            ),
+        ];
+
+        for (json, component) in matrix {
+            assert_eq!(from_str::<Component>(json).unwrap(), component);
+        }
+    }
+
+    #[test]
+    fn deserialize_related_card() {
+        let card: RelatedCard = from_str(
+            r#"{
+              "object": "related_card",
+              "id": "3ce8b1bb-0b01-4af6-b4e7-0b4b1f5dc76b",
+              "component": "meld_result",
+              "name": "Brisela, Voice of Nightmares",
+              "type_line": "Legendary Creature — Eldrazi Angel",
+              "uri": "https://api.scryfall.com/cards/3ce8b1bb-0b01-4af6-b4e7-0b4b1f5dc76b"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(card.component(), &Component::MeldResult);
+    }
 }
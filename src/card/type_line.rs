@@ -0,0 +1,159 @@
+//! Module defining a parsed type line.
+use std::fmt;
+
+/// The supertypes in the comprehensive rules. Any other word before the em
+/// dash in a type line is treated as a card type.
+const SUPERTYPES: [&str; 7] = [
+    "Basic",
+    "Elite",
+    "Host",
+    "Legendary",
+    "Ongoing",
+    "Snow",
+    "World",
+];
+
+/// A card's type line split into its supertypes, card types and subtypes.
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card::TypeLine;
+/// let type_line = TypeLine::parse("Legendary Creature — Elf Warrior").unwrap();
+/// assert_eq!(type_line.supertypes, ["Legendary"]);
+/// assert_eq!(type_line.card_types, ["Creature"]);
+/// assert_eq!(type_line.subtypes, ["Elf", "Warrior"]);
+/// assert_eq!(type_line.to_string(), "Legendary Creature — Elf Warrior");
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct TypeLine {
+    /// The supertypes, such as `Legendary` or `Basic`.
+    pub supertypes: Vec<String>,
+    /// The card types, such as `Creature` or `Instant`.
+    pub card_types: Vec<String>,
+    /// The subtypes after the em dash, such as `Elf` or `Equipment`.
+    pub subtypes: Vec<String>,
+}
+
+impl TypeLine {
+    /// Parses a type line as reported by Scryfall.
+    ///
+    /// The type lines of multi-faced cards, such as
+    /// `"Creature — Human Werewolf // Creature — Werewolf"`, are combined into
+    /// a single `TypeLine` with the types of every face, each listed once. Use
+    /// [`parse_faces`][TypeLine::parse_faces] to keep the faces apart.
+    ///
+    /// Returns `None` if the type line is empty.
+    pub fn parse(type_line: &str) -> Option<Self> {
+        let mut faces = TypeLine::parse_faces(type_line).into_iter();
+        let mut combined = faces.next()?;
+        for face in faces {
+            for (all, types) in [
+                (&mut combined.supertypes, face.supertypes),
+                (&mut combined.card_types, face.card_types),
+                (&mut combined.subtypes, face.subtypes),
+            ] {
+                for t in types {
+                    if !all.contains(&t) {
+                        all.push(t);
+                    }
+                }
+            }
+        }
+        Some(combined)
+    }
+
+    /// Parses each face of a type line separated by `//`, skipping empty
+    /// faces.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::TypeLine;
+    /// let faces = TypeLine::parse_faces("Instant // Sorcery — Adventure");
+    /// assert_eq!(faces.len(), 2);
+    /// assert_eq!(faces[1].subtypes, ["Adventure"]);
+    /// ```
+    pub fn parse_faces(type_line: &str) -> Vec<Self> {
+        type_line
+            .split("//")
+            .filter_map(TypeLine::parse_face)
+            .collect()
+    }
+
+    fn parse_face(face: &str) -> Option<Self> {
+        let mut halves = face.splitn(2, '—');
+        let types = halves.next().unwrap_or_default();
+        let subtypes = halves.next().unwrap_or_default();
+        if types.trim().is_empty() && subtypes.trim().is_empty() {
+            return None;
+        }
+
+        let words = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+        let (supertypes, card_types) = words(types)
+            .into_iter()
+            .partition(|t| SUPERTYPES.contains(&t.as_str()));
+        Some(TypeLine {
+            supertypes,
+            card_types,
+            subtypes: words(subtypes),
+        })
+    }
+
+    /// Checks if this type line has the card type `card_type`, ignoring case.
+    pub fn has_card_type(&self, card_type: &str) -> bool {
+        self.card_types
+            .iter()
+            .any(|t| t.eq_ignore_ascii_case(card_type))
+    }
+}
+
+impl fmt::Display for TypeLine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let types = self.supertypes.iter().chain(&self.card_types);
+        write!(f, "{}", itertools::join(types, " "))?;
+        if !self.subtypes.is_empty() {
+            write!(f, " — {}", self.subtypes.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_single_faces() {
+        let land = TypeLine::parse("Basic Snow Land — Forest").unwrap();
+        assert_eq!(land.supertypes, ["Basic", "Snow"]);
+        assert_eq!(land.card_types, ["Land"]);
+        assert_eq!(land.subtypes, ["Forest"]);
+
+        let instant = TypeLine::parse("Instant").unwrap();
+        assert!(instant.supertypes.is_empty());
+        assert_eq!(instant.card_types, ["Instant"]);
+        assert!(instant.subtypes.is_empty());
+        assert_eq!(instant.to_string(), "Instant");
+
+        let kindred = TypeLine::parse("Kindred Artifact — Eldrazi Equipment").unwrap();
+        assert_eq!(kindred.card_types, ["Kindred", "Artifact"]);
+        assert!(kindred.has_card_type("artifact"));
+        assert!(!kindred.has_card_type("Equipment"));
+
+        assert_eq!(TypeLine::parse(""), None);
+        assert_eq!(TypeLine::parse(" // "), None);
+    }
+
+    #[test]
+    fn parse_multiple_faces() {
+        let line = "Creature — Human Werewolf // Legendary Creature — Werewolf";
+        let faces = TypeLine::parse_faces(line);
+        assert_eq!(faces.len(), 2);
+        assert_eq!(faces[0].to_string(), "Creature — Human Werewolf");
+        assert_eq!(faces[1].to_string(), "Legendary Creature — Werewolf");
+
+        let combined = TypeLine::parse(line).unwrap();
+        assert_eq!(combined.supertypes, ["Legendary"]);
+        assert_eq!(combined.card_types, ["Creature"]);
+        assert_eq!(combined.subtypes, ["Human", "Werewolf"]);
+    }
+}
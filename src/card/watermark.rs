@@ -0,0 +1,173 @@
+//! Enum defining the watermarks a card can have.
+use std::fmt;
+
+/// A watermark printed in the text box of a card. For a full list of the
+/// watermarks Scryfall knows about, see
+/// [`Catalog::watermarks`][crate::catalog::Catalog::watermarks].
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card::Watermark;
+/// assert_eq!(Watermark::parse("boros"), Watermark::Boros);
+/// assert_eq!(Watermark::parse("Golgari"), Watermark::Golgari);
+/// assert_eq!(
+///     Watermark::parse("dnd"),
+///     Watermark::Other("dnd".to_string())
+/// );
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[allow(missing_docs)]
+#[non_exhaustive]
+pub enum Watermark {
+    /// The symbol of the set the card was printed in.
+    Set,
+    Azorius,
+    Boros,
+    Dimir,
+    Golgari,
+    Gruul,
+    Izzet,
+    Orzhov,
+    Rakdos,
+    Selesnya,
+    Simic,
+    Abzan,
+    Jeskai,
+    Mardu,
+    Sultai,
+    Temur,
+    Atarka,
+    Dromoka,
+    Kolaghan,
+    Ojutai,
+    Silumgar,
+    Lorehold,
+    Prismari,
+    Quandrix,
+    Silverquill,
+    Witherbloom,
+    Brokers,
+    Cabaretti,
+    Maestros,
+    Obscura,
+    Riveteers,
+    Mirran,
+    Phyrexian,
+    Planeswalker,
+    Colorpie,
+    /// Any other watermark, such as a set code or a promotional logo.
+    Other(String),
+}
+
+impl Watermark {
+    const ALL: [Watermark; 35] = [
+        Watermark::Set,
+        Watermark::Azorius,
+        Watermark::Boros,
+        Watermark::Dimir,
+        Watermark::Golgari,
+        Watermark::Gruul,
+        Watermark::Izzet,
+        Watermark::Orzhov,
+        Watermark::Rakdos,
+        Watermark::Selesnya,
+        Watermark::Simic,
+        Watermark::Abzan,
+        Watermark::Jeskai,
+        Watermark::Mardu,
+        Watermark::Sultai,
+        Watermark::Temur,
+        Watermark::Atarka,
+        Watermark::Dromoka,
+        Watermark::Kolaghan,
+        Watermark::Ojutai,
+        Watermark::Silumgar,
+        Watermark::Lorehold,
+        Watermark::Prismari,
+        Watermark::Quandrix,
+        Watermark::Silverquill,
+        Watermark::Witherbloom,
+        Watermark::Brokers,
+        Watermark::Cabaretti,
+        Watermark::Maestros,
+        Watermark::Obscura,
+        Watermark::Riveteers,
+        Watermark::Mirran,
+        Watermark::Phyrexian,
+        Watermark::Planeswalker,
+        Watermark::Colorpie,
+    ];
+
+    /// Parses a watermark as reported by Scryfall, ignoring case. Unknown
+    /// watermarks are kept as [`Watermark::Other`].
+    pub fn parse(value: &str) -> Self {
+        let value = value.trim();
+        Watermark::ALL
+            .iter()
+            .find(|watermark| watermark.to_string().eq_ignore_ascii_case(value))
+            .cloned()
+            .unwrap_or_else(|| Watermark::Other(value.to_ascii_lowercase()))
+    }
+}
+
+impl fmt::Display for Watermark {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Watermark::*;
+        write!(
+            f,
+            "{}",
+            match self {
+                Set => "set",
+                Azorius => "azorius",
+                Boros => "boros",
+                Dimir => "dimir",
+                Golgari => "golgari",
+                Gruul => "gruul",
+                Izzet => "izzet",
+                Orzhov => "orzhov",
+                Rakdos => "rakdos",
+                Selesnya => "selesnya",
+                Simic => "simic",
+                Abzan => "abzan",
+                Jeskai => "jeskai",
+                Mardu => "mardu",
+                Sultai => "sultai",
+                Temur => "temur",
+                Atarka => "atarka",
+                Dromoka => "dromoka",
+                Kolaghan => "kolaghan",
+                Ojutai => "ojutai",
+                Silumgar => "silumgar",
+                Lorehold => "lorehold",
+                Prismari => "prismari",
+                Quandrix => "quandrix",
+                Silverquill => "silverquill",
+                Witherbloom => "witherbloom",
+                Brokers => "brokers",
+                Cabaretti => "cabaretti",
+                Maestros => "maestros",
+                Obscura => "obscura",
+                Riveteers => "riveteers",
+                Mirran => "mirran",
+                Phyrexian => "phyrexian",
+                Planeswalker => "planeswalker",
+                Colorpie => "colorpie",
+                Other(s) => s,
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_round_trips() {
+        for watermark in Watermark::ALL.iter() {
+            assert_eq!(&Watermark::parse(&watermark.to_string()), watermark);
+        }
+        assert_eq!(Watermark::parse("BOROS"), Watermark::Boros);
+        assert_eq!(Watermark::parse("wotc").to_string(), "wotc");
+    }
+}
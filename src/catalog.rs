@@ -5,6 +5,8 @@
 //!
 //! Visit the official [docs](https://scryfall.com/docs/api/catalogs) for more documentation.
 
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
 use crate::uri::Uri;
@@ -26,7 +28,92 @@ pub struct Catalog {
     pub data: Vec<String>,
 }
 
+/// The catalogs provided by Scryfall, for use with [`Catalog::get`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum CatalogKind {
+    /// The `card-names` catalog. See [`Catalog::card_names`].
+    CardNames,
+    /// The `artist-names` catalog. See [`Catalog::artist_names`].
+    ArtistNames,
+    /// The `word-bank` catalog. See [`Catalog::word_bank`].
+    WordBank,
+    /// The `creature-types` catalog. See [`Catalog::creature_types`].
+    CreatureTypes,
+    /// The `planeswalker-types` catalog. See [`Catalog::planeswalker_types`].
+    PlaneswalkerTypes,
+    /// The `land-types` catalog. See [`Catalog::land_types`].
+    LandTypes,
+    /// The `artifact-types` catalog. See [`Catalog::artifact_types`].
+    ArtifactTypes,
+    /// The `enchantment-types` catalog. See [`Catalog::enchantment_types`].
+    EnchantmentTypes,
+    /// The `spell-types` catalog. See [`Catalog::spell_types`].
+    SpellTypes,
+    /// The `powers` catalog. See [`Catalog::powers`].
+    Powers,
+    /// The `toughnesses` catalog. See [`Catalog::toughnesses`].
+    Toughnesses,
+    /// The `loyalties` catalog. See [`Catalog::loyalties`].
+    Loyalties,
+    /// The `watermarks` catalog. See [`Catalog::watermarks`].
+    Watermarks,
+    /// The `keyword-abilities` catalog. See [`Catalog::keyword_abilities`].
+    KeywordAbilities,
+    /// The `keyword-actions` catalog. See [`Catalog::keyword_actions`].
+    KeywordActions,
+    /// The `ability-words` catalog. See [`Catalog::ability_words`].
+    AbilityWords,
+}
+
+impl fmt::Display for CatalogKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            CatalogKind::CardNames => "card-names",
+            CatalogKind::ArtistNames => "artist-names",
+            CatalogKind::WordBank => "word-bank",
+            CatalogKind::CreatureTypes => "creature-types",
+            CatalogKind::PlaneswalkerTypes => "planeswalker-types",
+            CatalogKind::LandTypes => "land-types",
+            CatalogKind::ArtifactTypes => "artifact-types",
+            CatalogKind::EnchantmentTypes => "enchantment-types",
+            CatalogKind::SpellTypes => "spell-types",
+            CatalogKind::Powers => "powers",
+            CatalogKind::Toughnesses => "toughnesses",
+            CatalogKind::Loyalties => "loyalties",
+            CatalogKind::Watermarks => "watermarks",
+            CatalogKind::KeywordAbilities => "keyword-abilities",
+            CatalogKind::KeywordActions => "keyword-actions",
+            CatalogKind::AbilityWords => "ability-words",
+        })
+    }
+}
+
 impl Catalog {
+    /// Fetches the values of the catalog of the specified kind.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::catalog::{Catalog, CatalogKind};
+    /// let types = Catalog::get(CatalogKind::CreatureTypes).unwrap();
+    /// assert!(types.iter().any(|t| t == "Goblin"));
+    /// ```
+    pub fn get(kind: CatalogKind) -> crate::Result<Vec<String>> {
+        Ok(Catalog::fetch(kind)?.data)
+    }
+
+    /// Fetches the whole catalog object of the specified kind, including its
+    /// `uri` and `total_values`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::catalog::{Catalog, CatalogKind};
+    /// let powers = Catalog::fetch(CatalogKind::Powers).unwrap();
+    /// assert_eq!(powers.total_values, powers.data.len());
+    /// ```
+    pub fn fetch(kind: CatalogKind) -> crate::Result<Self> {
+        Uri::from(CATALOG_URL.join(&kind.to_string())?).fetch()
+    }
+
     /// Returns a list of all nontoken English card names in Scryfall’s
     /// database. Values are updated as soon as a new card is entered for
     /// spoiler seasons.
@@ -34,10 +121,10 @@ impl Catalog {
     /// # Examples
     /// ```rust
     /// use scryfall::catalog::Catalog;
-    /// assert!(Catalog::card_names().unwrap().data.len() > 0)
+    /// assert!(Catalog::card_names().unwrap().len() > 0)
     /// ```
-    pub fn card_names() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("card-names")?).fetch()
+    pub fn card_names() -> crate::Result<Vec<String>> {
+        Catalog::get(CatalogKind::CardNames)
     }
 
     /// Returns a list of all canonical artist names in Scryfall’s database.
@@ -48,13 +135,13 @@ impl Catalog {
     /// # Examples
     /// ```rust
     /// use scryfall::catalog::Catalog;
-    /// assert!(Catalog::artist_names().unwrap().data.len() > 0)
+    /// assert!(Catalog::artist_names().unwrap().len() > 0)
     /// ```
-    pub fn artist_names() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("artist-names")?).fetch()
+    pub fn artist_names() -> crate::Result<Vec<String>> {
+        Catalog::get(CatalogKind::ArtistNames)
     }
 
-    /// Returns a Catalog of all English words, of length 2 or more, that could
+    /// Returns all English words, of length 2 or more, that could
     /// appear in a card name. Values are drawn from cards currently in
     /// Scryfall’s database. Values are updated as soon as a new card is
     /// entered for spoiler seasons.
@@ -62,138 +149,138 @@ impl Catalog {
     /// # Examples
     /// ```rust
     /// use scryfall::catalog::Catalog;
-    /// assert!(Catalog::word_bank().unwrap().data.len() > 0)
+    /// assert!(Catalog::word_bank().unwrap().len() > 0)
     /// ```
-    pub fn word_bank() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("word-bank")?).fetch()
+    pub fn word_bank() -> crate::Result<Vec<String>> {
+        Catalog::get(CatalogKind::WordBank)
     }
 
-    /// Returns a Catalog of all creature types in Scryfall’s database. Values
+    /// Returns all creature types in Scryfall’s database. Values
     /// are updated as soon as a new card is entered for spoiler seasons.
     ///
     /// # Examples
     /// ```rust
     /// use scryfall::catalog::Catalog;
-    /// assert!(Catalog::creature_types().unwrap().data.len() > 0)
+    /// assert!(Catalog::creature_types().unwrap().len() > 0)
     /// ```
-    pub fn creature_types() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("creature-types")?).fetch()
+    pub fn creature_types() -> crate::Result<Vec<String>> {
+        Catalog::get(CatalogKind::CreatureTypes)
     }
 
-    /// Returns a Catalog of all Planeswalker types in Scryfall’s database.
+    /// Returns all Planeswalker types in Scryfall’s database.
     /// Values are updated as soon as a new card is entered for spoiler
     /// seasons.
     ///
     /// # Examples
     /// ```rust
     /// use scryfall::catalog::Catalog;
-    /// assert!(Catalog::planeswalker_types().unwrap().data.len() > 0)
+    /// assert!(Catalog::planeswalker_types().unwrap().len() > 0)
     /// ```
-    pub fn planeswalker_types() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("planeswalker-types")?).fetch()
+    pub fn planeswalker_types() -> crate::Result<Vec<String>> {
+        Catalog::get(CatalogKind::PlaneswalkerTypes)
     }
 
-    /// Returns a Catalog of all Land types in Scryfall’s database. Values are
+    /// Returns all Land types in Scryfall’s database. Values are
     /// updated as soon as a new card is entered for spoiler seasons.
     ///
     /// # Examples
     /// ```rust
     /// use scryfall::catalog::Catalog;
-    /// assert!(Catalog::land_types().unwrap().data.len() > 0)
+    /// assert!(Catalog::land_types().unwrap().len() > 0)
     /// ```
-    pub fn land_types() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("land-types")?).fetch()
+    pub fn land_types() -> crate::Result<Vec<String>> {
+        Catalog::get(CatalogKind::LandTypes)
     }
 
-    /// Returns a Catalog of all artifact types in Scryfall’s database. Values
+    /// Returns all artifact types in Scryfall’s database. Values
     /// are updated as soon as a new card is entered for spoiler seasons.
     ///
     /// # Examples
     /// ```rust
     /// use scryfall::catalog::Catalog;
-    /// assert!(Catalog::artifact_types().unwrap().data.len() > 0)
+    /// assert!(Catalog::artifact_types().unwrap().len() > 0)
     /// ```
-    pub fn artifact_types() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("artifact-types")?).fetch()
+    pub fn artifact_types() -> crate::Result<Vec<String>> {
+        Catalog::get(CatalogKind::ArtifactTypes)
     }
 
-    /// Returns a Catalog of all enchantment types in Scryfall’s database.
+    /// Returns all enchantment types in Scryfall’s database.
     /// Values are updated as soon as a new card is entered for spoiler
     /// seasons.
     ///
     /// # Examples
     /// ```rust
     /// use scryfall::catalog::Catalog;
-    /// assert!(Catalog::enchantment_types().unwrap().data.len() > 0)
+    /// assert!(Catalog::enchantment_types().unwrap().len() > 0)
     /// ```
-    pub fn enchantment_types() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("enchantment-types")?).fetch()
+    pub fn enchantment_types() -> crate::Result<Vec<String>> {
+        Catalog::get(CatalogKind::EnchantmentTypes)
     }
 
-    /// Returns a Catalog of all spell types in Scryfall’s database. Values are
+    /// Returns all spell types in Scryfall’s database. Values are
     /// updated as soon as a new card is entered for spoiler seasons.
     ///
     /// # Examples
     /// ```rust
     /// use scryfall::catalog::Catalog;
-    /// assert!(Catalog::spell_types().unwrap().data.len() > 0)
+    /// assert!(Catalog::spell_types().unwrap().len() > 0)
     /// ```
-    pub fn spell_types() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("spell-types")?).fetch()
+    pub fn spell_types() -> crate::Result<Vec<String>> {
+        Catalog::get(CatalogKind::SpellTypes)
     }
 
-    /// Returns a Catalog of all possible values for a creature or vehicle’s
+    /// Returns all possible values for a creature or vehicle’s
     /// power in Scryfall’s database. Values are updated as soon as a new
     /// card is entered for spoiler seasons.
     ///
     /// # Examples
     /// ```rust
     /// use scryfall::catalog::Catalog;
-    /// assert!(Catalog::powers().unwrap().data.len() > 0)
+    /// assert!(Catalog::powers().unwrap().len() > 0)
     /// ```
-    pub fn powers() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("powers")?).fetch()
+    pub fn powers() -> crate::Result<Vec<String>> {
+        Catalog::get(CatalogKind::Powers)
     }
 
-    /// Returns a Catalog of all possible values for a creature or vehicle’s
+    /// Returns all possible values for a creature or vehicle’s
     /// toughness in Scryfall’s database. Values are updated as soon as a
     /// new card is entered for spoiler seasons.
     ///
     /// # Examples
     /// ```rust
     /// use scryfall::catalog::Catalog;
-    /// assert!(Catalog::toughnesses().unwrap().data.len() > 0)
+    /// assert!(Catalog::toughnesses().unwrap().len() > 0)
     /// ```
-    pub fn toughnesses() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("toughnesses")?).fetch()
+    pub fn toughnesses() -> crate::Result<Vec<String>> {
+        Catalog::get(CatalogKind::Toughnesses)
     }
 
-    /// Returns a Catalog of all possible values for a Planeswalker’s loyalty in
+    /// Returns all possible values for a Planeswalker’s loyalty in
     /// Scryfall’s database. Values are updated as soon as a new card is
     /// entered for spoiler seasons.
     ///
     /// # Examples
     /// ```rust
     /// use scryfall::catalog::Catalog;
-    /// assert!(Catalog::loyalties().unwrap().data.len() > 0)
+    /// assert!(Catalog::loyalties().unwrap().len() > 0)
     /// ```
-    pub fn loyalties() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("loyalties")?).fetch()
+    pub fn loyalties() -> crate::Result<Vec<String>> {
+        Catalog::get(CatalogKind::Loyalties)
     }
 
-    /// Returns a Catalog of all card watermarks in Scryfall’s database. Values
+    /// Returns all card watermarks in Scryfall’s database. Values
     /// are updated as soon as a new card is entered for spoiler seasons.
     ///
     /// # Examples
     /// ```rust
     /// use scryfall::catalog::Catalog;
-    /// assert!(Catalog::watermarks().unwrap().data.len() > 0)
+    /// assert!(Catalog::watermarks().unwrap().len() > 0)
     /// ```
-    pub fn watermarks() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("watermarks")?).fetch()
+    pub fn watermarks() -> crate::Result<Vec<String>> {
+        Catalog::get(CatalogKind::Watermarks)
     }
 
-    /// Returns a Catalog of all keyword abilities in Scryfall’s database.
+    /// Returns all keyword abilities in Scryfall’s database.
     /// Values are updated as soon as a new card is entered for spoiler seasons.
     ///
     /// # Examples
@@ -202,17 +289,16 @@ impl Catalog {
     /// assert!(
     ///     Catalog::keyword_abilities()
     ///         .unwrap()
-    ///         .data
     ///         .iter()
     ///         .find(|a| a.as_str() == "Haste")
     ///         .is_some()
     /// );
     /// ```
-    pub fn keyword_abilities() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("keyword-abilities")?).fetch()
+    pub fn keyword_abilities() -> crate::Result<Vec<String>> {
+        Catalog::get(CatalogKind::KeywordAbilities)
     }
 
-    /// Returns a Catalog of all keyword actions in Scryfall’s database. Values
+    /// Returns all keyword actions in Scryfall’s database. Values
     /// are updated as soon as a new card is entered for spoiler seasons.
     ///
     /// # Examples
@@ -221,17 +307,16 @@ impl Catalog {
     /// assert!(
     ///     Catalog::keyword_actions()
     ///         .unwrap()
-    ///         .data
     ///         .iter()
     ///         .find(|a| a.as_str() == "Scry")
     ///         .is_some()
     /// );
     /// ```
-    pub fn keyword_actions() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("keyword-actions")?).fetch()
+    pub fn keyword_actions() -> crate::Result<Vec<String>> {
+        Catalog::get(CatalogKind::KeywordActions)
     }
 
-    /// Returns a Catalog of all ability words in Scryfall’s database. Values
+    /// Returns all ability words in Scryfall’s database. Values
     /// are updated as soon as a new card is entered for spoiler seasons.
     ///
     /// # Examples
@@ -240,13 +325,12 @@ impl Catalog {
     /// assert!(
     ///     Catalog::ability_words()
     ///         .unwrap()
-    ///         .data
     ///         .iter()
     ///         .find(|a| a.as_str() == "Landfall")
     ///         .is_some()
     /// );
     /// ```
-    pub fn ability_words() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("ability-words")?).fetch()
+    pub fn ability_words() -> crate::Result<Vec<String>> {
+        Catalog::get(CatalogKind::AbilityWords)
     }
 }
@@ -0,0 +1,801 @@
+//! A configurable client for the Scryfall API.
+//!
+//! All requests made by this crate go through a [`ScryfallClient`]. Functions
+//! like [`Card::named`] use a default client that talks to
+//! `https://api.scryfall.com/`, but a client can be built with a custom
+//! [`ureq::Agent`], to add headers or proxies, or with a different base URL,
+//! to run tests against a mock server.
+//!
+//! # Examples
+//! ```rust,no_run
+//! use scryfall::client::ScryfallClient;
+//! # fn main() -> scryfall::Result<()> {
+//! let client = ScryfallClient::new().base_url("http://localhost:8080/".parse()?);
+//! let bolt = client.named("Lightning Bolt")?;
+//! # Ok(())
+//! # }
+//! ```
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde::de::DeserializeOwned;
+use ureq::Agent;
+use url::Url;
+
+use crate::card::Card;
+use crate::error::Error;
+use crate::list::{List, ListIter};
+use crate::search::Search;
+use crate::uri::{Uri, DEFAULT_USER_AGENT};
+use crate::util::{Uuid, CARDS_URL, ROOT_URL};
+
+thread_local!(static DEFAULT_CLIENT: ScryfallClient = ScryfallClient {
+    user_agent: UserAgent::Global,
+    ..ScryfallClient::new()
+});
+
+/// A client that sends requests to the Scryfall API.
+///
+/// Cloning a client is cheap, and the clones share the same connection pool
+/// and response cache.
+#[derive(Clone, Debug)]
+pub struct ScryfallClient {
+    agent: Agent,
+    base_url: Url,
+    retry_policy: RetryPolicy,
+    cache: Option<Arc<Mutex<ResponseCache>>>,
+    user_agent: UserAgent,
+}
+
+/// Where the `User-Agent` header of a client's requests comes from.
+#[derive(Clone, Debug)]
+enum UserAgent {
+    /// The one set with [`set_user_agent`][crate::uri::set_user_agent].
+    Global,
+    /// One set with [`ScryfallClient::user_agent`].
+    Custom(String),
+    /// Whatever the [`Agent`] sends.
+    Agent,
+}
+
+impl Default for ScryfallClient {
+    fn default() -> Self {
+        ScryfallClient::new()
+    }
+}
+
+impl ScryfallClient {
+    /// Creates a new client that talks to `https://api.scryfall.com/`, with
+    /// [`DEFAULT_USER_AGENT`] as its user agent.
+    pub fn new() -> Self {
+        ScryfallClient::with_agent(Agent::new()).user_agent(DEFAULT_USER_AGENT)
+    }
+
+    /// Creates a new client that sends its requests with `agent`.
+    ///
+    /// Requests keep the `User-Agent` header configured on `agent`, unless
+    /// [`user_agent`][ScryfallClient::user_agent] is called.
+    pub fn with_agent(agent: Agent) -> Self {
+        ScryfallClient {
+            agent,
+            base_url: ROOT_URL.clone(),
+            retry_policy: RetryPolicy::default(),
+            cache: None,
+            user_agent: UserAgent::Agent,
+        }
+    }
+
+    /// Sets the `User-Agent` header sent with this client's requests. Scryfall
+    /// asks applications to identify themselves with it, and may reject
+    /// requests that don't.
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = UserAgent::Custom(user_agent.to_string());
+        self
+    }
+
+    /// Sets the base URL of the API. Requests that would go to
+    /// `https://api.scryfall.com/` go to this URL instead.
+    pub fn base_url(mut self, mut base_url: Url) -> Self {
+        if !base_url.path().ends_with('/') {
+            let path = format!("{}/", base_url.path());
+            base_url.set_path(&path);
+        }
+        self.base_url = base_url;
+        self
+    }
+
+    /// Sets how requests that fail with `429 Too Many Requests` or a transient
+    /// server error are retried.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Keeps the responses to this client's requests in memory, so fetching
+    /// the same URL again doesn't send another request. Up to `capacity`
+    /// responses are kept, each for at most `ttl`. When the cache is full, the
+    /// least recently used response is dropped.
+    ///
+    /// Only successful responses that were deserialized without errors are
+    /// cached. The functions that don't take a client, like [`Card::named`],
+    /// never use a cache.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use scryfall::client::ScryfallClient;
+    /// let client = ScryfallClient::new().cache(100, Duration::from_secs(60 * 60));
+    /// let bolt = client.named("Lightning Bolt").unwrap();
+    /// // This doesn't send a request.
+    /// assert_eq!(client.named("Lightning Bolt").unwrap(), bolt);
+    /// ```
+    pub fn cache(mut self, capacity: usize, ttl: Duration) -> Self {
+        self.cache = Some(Arc::new(Mutex::new(ResponseCache::new(capacity, ttl))));
+        self
+    }
+
+    /// Runs `f` with the client used by the functions that don't take one.
+    pub(crate) fn with_default<R>(f: impl FnOnce(&ScryfallClient) -> R) -> R {
+        DEFAULT_CLIENT.with(f)
+    }
+
+    pub(crate) fn agent(&self) -> &Agent {
+        &self.agent
+    }
+
+    pub(crate) fn get_retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// The `User-Agent` header to set on requests, if the agent's own
+    /// shouldn't be used.
+    pub(crate) fn get_user_agent(&self) -> Option<String> {
+        match &self.user_agent {
+            UserAgent::Global => Some(crate::uri::global_user_agent()),
+            UserAgent::Custom(user_agent) => Some(user_agent.clone()),
+            UserAgent::Agent => None,
+        }
+    }
+
+    /// Returns the cached body of the response to `url`, if there is one.
+    pub(crate) fn cached(&self, url: &Url) -> Option<Arc<[u8]>> {
+        let cache = self.cache.as_ref()?;
+        let mut cache = cache.lock().unwrap_or_else(|e| e.into_inner());
+        cache.get(url, Instant::now())
+    }
+
+    /// Caches the body of the response to `url`, if this client has a cache.
+    pub(crate) fn store(&self, url: &Url, body: Arc<[u8]>) {
+        if let Some(cache) = &self.cache {
+            let mut cache = cache.lock().unwrap_or_else(|e| e.into_inner());
+            cache.insert(url.clone(), body, Instant::now());
+        }
+    }
+
+    pub(crate) fn has_cache(&self) -> bool {
+        self.cache.is_some()
+    }
+
+    /// The URL a request to `url` should actually be sent to, taking the base
+    /// URL of this client into account.
+    pub(crate) fn resolve(&self, url: &Url) -> crate::Result<Url> {
+        match url.as_str().strip_prefix(ROOT_URL.as_str()) {
+            Some(path) if self.base_url != *ROOT_URL => Ok(self.base_url.join(path)?),
+            _ => Ok(url.clone()),
+        }
+    }
+
+    /// Fetches a resource from the API with this client. See
+    /// [`Uri::fetch`].
+    pub fn fetch<T: DeserializeOwned>(&self, uri: &Uri<T>) -> crate::Result<T> {
+        uri.fetch_with(self)
+    }
+
+    /// Lazily iterates over items from all pages of a list with this client.
+    /// Following pages are requested with this client too. See
+    /// [`Uri::fetch_iter`].
+    pub fn fetch_iter<T: DeserializeOwned>(
+        &self,
+        uri: &Uri<List<T>>,
+    ) -> crate::Result<ListIter<T>> {
+        uri.fetch_iter_with(self)
+    }
+
+    /// Eagerly fetches items from all pages of a list with this client. See
+    /// [`Uri::fetch_all`].
+    pub fn fetch_all<T: DeserializeOwned>(&self, uri: &Uri<List<T>>) -> crate::Result<Vec<T>> {
+        uri.fetch_all_with(self)
+    }
+
+    /// Fetches a random card. See [`Card::random`].
+    ///
+    /// Random cards are never cached, even if this client has a
+    /// [cache][ScryfallClient::cache].
+    pub fn random(&self) -> crate::Result<Card> {
+        Uri::from(CARDS_URL.join("random/")?).fetch_uncached_with(self)
+    }
+
+    /// Returns a [`ListIter`] of the cards that match the search terms. See
+    /// [`Card::search`].
+    pub fn search(&self, query: impl Search) -> crate::Result<ListIter<Card>> {
+        let iter = self.fetch_iter(&search_uri(&query)?)?;
+        Ok(match query.max_pages() {
+            Some(pages) => iter.page_limit(pages),
+            None => iter,
+        })
+    }
+
+    /// Returns the first page of cards that match the search terms. See
+    /// [`Card::search_page`].
+    pub fn search_page(&self, query: impl Search) -> crate::Result<List<Card>> {
+        self.fetch(&search_uri(query)?)
+    }
+
+    /// Returns all cards that match a query, as a `Vec`. See
+    /// [`Card::search_all`].
+    pub fn search_all(&self, query: impl Search) -> crate::Result<Vec<Card>> {
+        if query.max_pages().is_some() {
+            return self.search(query)?.collect();
+        }
+        self.fetch_all(&search_uri(query)?)
+    }
+
+    /// Fetches a random card matching a search query. See
+    /// [`Card::random_with`].
+    ///
+    /// Random cards are never cached, even if this client has a
+    /// [cache][ScryfallClient::cache].
+    pub fn random_with(&self, query: impl Search) -> crate::Result<Card> {
+        let mut url = CARDS_URL.join("random/")?;
+        query.write_random_query(&mut url)?;
+        Uri::from(url).fetch_uncached_with(self)
+    }
+
+    /// Fetches a random card matching a search query. See
+    /// [`Card::search_random`].
+    pub fn search_random(&self, query: impl Search) -> crate::Result<Card> {
+        self.random_with(query)
+    }
+
+    /// Returns a card with the exact name. See [`Card::named`].
+    pub fn named(&self, name: &str) -> crate::Result<Card> {
+        self.fetch(&named_uri("exact", name)?)
+    }
+
+    /// Returns a card using the scryfall fuzzy finder. See
+    /// [`Card::named_fuzzy`].
+    pub fn named_fuzzy(&self, query: &str) -> crate::Result<Card> {
+        self.fetch(&named_uri("fuzzy", query)?)
+    }
+
+    /// Fetches a card by its Scryfall id. See [`Card::card`].
+    pub fn card(&self, scryfall_id: Uuid) -> crate::Result<Card> {
+        self.fetch(&Uri::from(CARDS_URL.join(&scryfall_id.to_string())?))
+    }
+
+    /// Fetches a representative printing of the card with the given Oracle id.
+    /// See [`Card::oracle`].
+    pub fn oracle(&self, oracle_id: Uuid) -> crate::Result<Card> {
+        self.search_page(format!("oracleid:{}", oracle_id))?
+            .data
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::Other(format!("No card with oracle id {}", oracle_id)))
+    }
+}
+
+/// The URI of the cards that match `query`. Also used by the async functions,
+/// like `Card::search_async`.
+pub(crate) fn search_uri(query: impl Search) -> crate::Result<Uri<List<Card>>> {
+    let mut url = CARDS_URL.join("search/")?;
+    query.write_query(&mut url)?;
+    Ok(Uri::from(url))
+}
+
+/// The URI of the card named `name`, where `mode` is `exact` or `fuzzy`. Also
+/// used by the async functions, like `Card::named_async`.
+pub(crate) fn named_uri(mode: &str, name: &str) -> crate::Result<Uri<Card>> {
+    let mut url = CARDS_URL.join("named")?;
+    url.query_pairs_mut().append_pair(mode, name);
+    Ok(Uri::from(url))
+}
+
+/// Describes how a [`ScryfallClient`] retries requests that fail with `429 Too
+/// Many Requests` or a transient server error (500, 502, 503 or 504).
+///
+/// If the response has a `Retry-After` header, the client waits that long
+/// before retrying. Otherwise, it waits for an exponentially growing delay,
+/// with some random jitter. Other errors, like `404 Not Found`, are never
+/// retried.
+///
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+///
+/// use scryfall::client::{RetryPolicy, ScryfallClient};
+/// let client = ScryfallClient::new().retry_policy(
+///     RetryPolicy::new()
+///         .max_attempts(5)
+///         .base_delay(Duration::from_millis(250)),
+/// );
+/// let impatient = ScryfallClient::new().retry_policy(RetryPolicy::disabled());
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::new()
+    }
+}
+
+impl RetryPolicy {
+    /// Creates the default retry policy, which makes up to 3 attempts,
+    /// starting with a delay of 500 milliseconds.
+    pub const fn new() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+        }
+    }
+
+    /// Creates a retry policy that never retries.
+    pub const fn disabled() -> Self {
+        RetryPolicy::new().max_attempts(1)
+    }
+
+    /// Sets the maximum number of attempts made for a request, including the
+    /// first one. A value of 0 is treated as 1.
+    pub const fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Sets the delay before the first retry, when the response doesn't have
+    /// a `Retry-After` header. Each following retry waits twice as long.
+    pub const fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Checks if a request that got a response with `status` on its
+    /// `attempt`-th attempt should be tried again.
+    pub(crate) fn should_retry(&self, status: u16, attempt: u32) -> bool {
+        matches!(status, 429 | 500 | 502 | 503 | 504) && attempt < self.max_attempts
+    }
+
+    /// How long to wait after the `attempt`-th attempt failed, given the
+    /// value of the response's `Retry-After` header.
+    pub(crate) fn delay(&self, attempt: u32, retry_after: Option<&str>) -> Duration {
+        if let Some(seconds) = retry_after.and_then(|s| s.trim().parse().ok()) {
+            return Duration::from_secs(seconds);
+        }
+        let backoff = self.base_delay * 2u32.saturating_pow(attempt.saturating_sub(1));
+        let jitter = RandomState::new().build_hasher().finish() % 1000;
+        backoff + backoff / 2 * jitter as u32 / 1000
+    }
+}
+
+/// The bodies of recent responses, keyed by the URL they were requested
+/// from. See [`ScryfallClient::cache`].
+#[derive(Debug)]
+struct ResponseCache {
+    capacity: usize,
+    ttl: Duration,
+    entries: HashMap<Url, CacheEntry>,
+    uses: u64,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    body: Arc<[u8]>,
+    expires: Instant,
+    last_use: u64,
+}
+
+impl ResponseCache {
+    fn new(capacity: usize, ttl: Duration) -> Self {
+        ResponseCache {
+            capacity,
+            ttl,
+            entries: HashMap::new(),
+            uses: 0,
+        }
+    }
+
+    /// Returns the body cached for `url`, unless it expired before `now`.
+    fn get(&mut self, url: &Url, now: Instant) -> Option<Arc<[u8]>> {
+        let entry = self.entries.get_mut(url)?;
+        if entry.expires <= now {
+            self.entries.remove(url);
+            return None;
+        }
+        self.uses += 1;
+        entry.last_use = self.uses;
+        Some(Arc::clone(&entry.body))
+    }
+
+    /// Caches `body` for `url`, dropping the least recently used entry if the
+    /// cache is full.
+    fn insert(&mut self, url: Url, body: Arc<[u8]>, now: Instant) {
+        if self.capacity == 0 {
+            return;
+        }
+        if !self.entries.contains_key(&url) && self.entries.len() >= self.capacity {
+            self.entries.retain(|_, entry| entry.expires > now);
+            if self.entries.len() >= self.capacity {
+                let oldest = self
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.last_use)
+                    .map(|(url, _)| url.clone());
+                if let Some(oldest) = oldest {
+                    self.entries.remove(&oldest);
+                }
+            }
+        }
+        self.uses += 1;
+        self.entries.insert(
+            url,
+            CacheEntry {
+                body,
+                expires: now + self.ttl,
+                last_use: self.uses,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    use super::*;
+    use crate::catalog::Catalog;
+    use crate::error::Error;
+    use crate::search::prelude::*;
+
+    const NOT_FOUND: &str = "404 Not Found";
+    const NOT_FOUND_BODY: &str =
+        r#"{"object":"error","status":404,"code":"not_found","details":"No card found"}"#;
+
+    /// Starts a server that answers one request per response in `responses`,
+    /// which are pairs of status line and body. `{url}` in a body is replaced
+    /// with the URL of the server. The server returns the request lines it
+    /// received.
+    fn serve(responses: Vec<(&'static str, &'static str)>) -> (Url, JoinHandle<Vec<String>>) {
+        let (url, server) = serve_with_headers(responses);
+        let server = thread::spawn(move || {
+            let requests = server.join().unwrap();
+            requests.into_iter().map(|(line, _)| line).collect()
+        });
+        (url, server)
+    }
+
+    /// A request line and the headers that followed it.
+    type ReceivedRequest = (String, Vec<String>);
+
+    /// Like [`serve`], but the server also returns the headers of each
+    /// request, without the trailing `\r\n`.
+    fn serve_with_headers(
+        responses: Vec<(&'static str, &'static str)>,
+    ) -> (Url, JoinHandle<Vec<ReceivedRequest>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let base = url.clone();
+        let server = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut headers = Vec::new();
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    headers.push(line.trim_end().to_string());
+                    line.clear();
+                }
+                let body = body.replace("{url}", &base);
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nRetry-After: 0\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+                requests.push((request_line, headers));
+            }
+            requests
+        });
+        (url.parse().unwrap(), server)
+    }
+
+    #[test]
+    fn resolve_against_base_url() {
+        let url = CARDS_URL.join("named?exact=Lightning+Bolt").unwrap();
+        assert_eq!(ScryfallClient::new().resolve(&url).unwrap(), url);
+
+        let client = ScryfallClient::new().base_url("http://localhost:8080/api".parse().unwrap());
+        assert_eq!(
+            client.resolve(&url).unwrap().as_str(),
+            "http://localhost:8080/api/cards/named?exact=Lightning+Bolt"
+        );
+
+        let other = Url::parse("https://example.com/cards/named").unwrap();
+        assert_eq!(client.resolve(&other).unwrap(), other);
+    }
+
+    #[test]
+    fn requests_go_to_base_url() {
+        let (url, server) = serve(vec![(NOT_FOUND, NOT_FOUND_BODY)]);
+        let client = ScryfallClient::new().base_url(url);
+        match client.named("Not a card") {
+            Err(Error::ScryfallError(e)) => assert_eq!(e.details, "No card found"),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+        assert_eq!(
+            server.join().unwrap(),
+            ["GET /cards/named?exact=Not+a+card HTTP/1.1\r\n"]
+        );
+    }
+
+    /// The headers named `name` in `headers`.
+    fn header<'a>(headers: &'a [String], name: &str) -> Vec<&'a str> {
+        headers
+            .iter()
+            .filter_map(|h| h.strip_prefix(name)?.strip_prefix(": "))
+            .collect()
+    }
+
+    #[test]
+    fn sends_user_agent_and_accept() {
+        let (url, server) = serve_with_headers(vec![(NOT_FOUND, NOT_FOUND_BODY)]);
+        let client = ScryfallClient::new().base_url(url);
+        assert!(client.named("Not a card").is_err());
+        let (_, headers) = server.join().unwrap().remove(0);
+        assert_eq!(header(&headers, "User-Agent"), [DEFAULT_USER_AGENT]);
+        assert_eq!(
+            header(&headers, "Accept"),
+            ["application/json;q=0.9,*/*;q=0.8"]
+        );
+    }
+
+    #[test]
+    fn user_agent_is_per_client() {
+        let (url, server) = serve_with_headers(vec![
+            (NOT_FOUND, NOT_FOUND_BODY),
+            (NOT_FOUND, NOT_FOUND_BODY),
+            (NOT_FOUND, NOT_FOUND_BODY),
+        ]);
+        let agent = ureq::AgentBuilder::new().user_agent("my-agent/1.0").build();
+        let clients = [
+            ScryfallClient::with_agent(agent.clone()),
+            ScryfallClient::with_agent(agent).user_agent("my-app/2.0"),
+            ScryfallClient::new().user_agent("my-app/3.0"),
+        ];
+        for client in &clients {
+            let client = client.clone().base_url(url.clone());
+            assert!(client.named("Not a card").is_err());
+        }
+        let user_agents: Vec<_> = server
+            .join()
+            .unwrap()
+            .iter()
+            .map(|(_, headers)| header(headers, "User-Agent").join(", "))
+            .collect();
+        assert_eq!(user_agents, ["my-agent/1.0", "my-app/2.0", "my-app/3.0"]);
+    }
+
+    #[test]
+    fn json_errors_include_url_and_body() {
+        let (url, server) = serve(vec![("200 OK", r#"{"object":"card"}"#)]);
+        let client = ScryfallClient::new().base_url(url);
+        let error = client.named("Lightning Bolt").unwrap_err();
+        assert!(
+            matches!(error, Error::ResponseJsonError { .. }),
+            "{:?}",
+            error
+        );
+        assert!(error
+            .url()
+            .unwrap()
+            .ends_with("/cards/named?exact=Lightning+Bolt"));
+        assert!(error.to_string().contains(r#"body: {"object":"card"}"#));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn retries_transient_errors() {
+        let catalog = r#"{"object":"catalog","uri":"https://api.scryfall.com/catalog/powers","total_values":1,"data":["1"]}"#;
+        let (url, server) = serve(vec![
+            ("429 Too Many Requests", NOT_FOUND_BODY),
+            ("503 Service Unavailable", NOT_FOUND_BODY),
+            ("200 OK", catalog),
+        ]);
+        let client = ScryfallClient::new().base_url(url);
+        let powers: Catalog = client
+            .fetch(&Uri::from(ROOT_URL.join("catalog/powers").unwrap()))
+            .unwrap();
+        assert_eq!(powers.data, ["1"]);
+        assert_eq!(server.join().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn gives_up_after_max_attempts() {
+        let (url, server) = serve(vec![("503 Service Unavailable", NOT_FOUND_BODY)]);
+        let client = ScryfallClient::new()
+            .base_url(url)
+            .retry_policy(RetryPolicy::disabled());
+        assert!(matches!(
+            client.named("Not a card"),
+            Err(Error::ScryfallError(_))
+        ));
+        assert_eq!(server.join().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn search_stops_at_page_limit() {
+        let page = r#"{"object":"list","has_more":true,"next_page":"https://api.scryfall.com/cards/search?page=2&q=t%3Agoblin","data":[]}"#;
+        let (url, server) = serve(vec![("200 OK", page), ("200 OK", page)]);
+        let client = ScryfallClient::new().base_url(url);
+        let mut options = SearchOptions::with_query(type_line("goblin"));
+        options.page_limit(1);
+        assert!(client.search_all(&options).unwrap().is_empty());
+        assert_eq!(client.search(&options).unwrap().count(), 0);
+        assert_eq!(
+            server.join().unwrap(),
+            [
+                "GET /cards/search/?page=1&q=type%3A%22goblin%22 HTTP/1.1\r\n",
+                "GET /cards/search/?page=1&q=type%3A%22goblin%22 HTTP/1.1\r\n",
+            ]
+        );
+    }
+
+    #[test]
+    fn cached_responses_are_reused() {
+        let catalog = r#"{"object":"catalog","uri":"https://api.scryfall.com/catalog/powers","total_values":1,"data":["1"]}"#;
+        let (url, server) = serve(vec![
+            ("200 OK", catalog),
+            (NOT_FOUND, NOT_FOUND_BODY),
+            (NOT_FOUND, NOT_FOUND_BODY),
+        ]);
+        let client = ScryfallClient::new()
+            .base_url(url)
+            .cache(10, Duration::from_secs(60));
+        let powers = Uri::<Catalog>::from(ROOT_URL.join("catalog/powers").unwrap());
+        assert_eq!(client.fetch(&powers).unwrap().data, ["1"]);
+        assert_eq!(client.clone().fetch(&powers).unwrap().data, ["1"]);
+        // Errors are not cached.
+        assert!(client.named("Not a card").is_err());
+        assert!(client.named("Not a card").is_err());
+        assert_eq!(
+            server.join().unwrap(),
+            [
+                "GET /catalog/powers HTTP/1.1\r\n",
+                "GET /cards/named?exact=Not+a+card HTTP/1.1\r\n",
+                "GET /cards/named?exact=Not+a+card HTTP/1.1\r\n",
+            ]
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn streams_pages_async() {
+        use futures::TryStreamExt;
+
+        let (url, server) = serve(vec![
+            (
+                "200 OK",
+                r#"{"object":"list","has_more":true,"next_page":"{url}page/2","data":["a","b"]}"#,
+            ),
+            (
+                "200 OK",
+                r#"{"object":"list","has_more":false,"data":["c"]}"#,
+            ),
+            (NOT_FOUND, NOT_FOUND_BODY),
+        ]);
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let first = Uri::<List<String>>::from(url.join("page/1").unwrap());
+        let pages: Vec<_> = runtime
+            .block_on(first.fetch_pages_async().try_collect::<Vec<_>>())
+            .unwrap()
+            .into_iter()
+            .map(|page| page.data)
+            .collect();
+        assert_eq!(pages, [vec!["a", "b"], vec!["c"]]);
+
+        let missing = Uri::<List<String>>::from(url.join("missing").unwrap());
+        match runtime.block_on(missing.fetch_pages_async().try_collect::<Vec<_>>()) {
+            Err(Error::ScryfallError(e)) => assert_eq!(e.details, "No card found"),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+        assert_eq!(
+            server.join().unwrap(),
+            [
+                "GET /page/1 HTTP/1.1\r\n",
+                "GET /page/2 HTTP/1.1\r\n",
+                "GET /missing HTTP/1.1\r\n",
+            ]
+        );
+    }
+
+    #[test]
+    fn random_cards_are_not_cached() {
+        let card = |name: &str| -> &'static str {
+            let mut json = crate::card::tests::card_json();
+            json["name"] = name.into();
+            Box::leak(json.to_string().into_boxed_str())
+        };
+        let (url, server) = serve(vec![
+            ("200 OK", card("Storm Crow")),
+            ("200 OK", card("Mindstorm Crown")),
+            ("200 OK", card("Lightning Bolt")),
+            ("200 OK", card("Shock")),
+        ]);
+        let client = ScryfallClient::new()
+            .base_url(url)
+            .cache(10, Duration::from_secs(60));
+        assert_eq!(client.random().unwrap().name, "Storm Crow");
+        assert_eq!(client.random().unwrap().name, "Mindstorm Crown");
+        assert_eq!(
+            client.random_with("t:instant").unwrap().name,
+            "Lightning Bolt"
+        );
+        assert_eq!(client.search_random("t:instant").unwrap().name, "Shock");
+        assert_eq!(server.join().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn cache_evicts_expired_and_least_recently_used() {
+        let url = |path| ROOT_URL.join(path).unwrap();
+        let body = |s: &str| Arc::from(s.as_bytes());
+        let now = Instant::now();
+        let mut cache = ResponseCache::new(2, Duration::from_secs(10));
+        cache.insert(url("a"), body("a"), now);
+        cache.insert(url("b"), body("b"), now);
+        assert!(cache.get(&url("a"), now).is_some());
+        cache.insert(url("c"), body("c"), now);
+        assert!(cache.get(&url("b"), now).is_none());
+        assert_eq!(cache.get(&url("a"), now).as_deref(), Some(&b"a"[..]));
+        assert_eq!(cache.get(&url("c"), now).as_deref(), Some(&b"c"[..]));
+
+        let later = now + Duration::from_secs(10);
+        assert!(cache.get(&url("a"), later).is_none());
+        assert_eq!(cache.entries.len(), 1);
+
+        let mut disabled = ResponseCache::new(0, Duration::from_secs(10));
+        disabled.insert(url("a"), body("a"), now);
+        assert!(disabled.get(&url("a"), now).is_none());
+    }
+
+    #[test]
+    fn retry_delays() {
+        let policy = RetryPolicy::new().base_delay(Duration::from_millis(100));
+        assert!(policy.should_retry(429, 1));
+        assert!(policy.should_retry(503, 2));
+        assert!(!policy.should_retry(503, 3));
+        assert!(!policy.should_retry(404, 1));
+        assert!(!RetryPolicy::disabled().should_retry(429, 1));
+
+        assert_eq!(policy.delay(1, Some("2")), Duration::from_secs(2));
+        for attempt in 1..4 {
+            let backoff = Duration::from_millis(100) * 2u32.pow(attempt - 1);
+            let delay = policy.delay(attempt, None);
+            assert!(delay >= backoff && delay <= backoff * 3 / 2, "{:?}", delay);
+        }
+    }
+}
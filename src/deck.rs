@@ -0,0 +1,278 @@
+//! Decklists in the text format used by MTGO and Arena, resolved into cards
+//! with the [collection](https://scryfall.com/docs/api/cards/collection)
+//! endpoint.
+//!
+//! Each line of a decklist holds a quantity and a card name, optionally
+//! followed by a set code in parentheses and a collector number:
+//!
+//! ```text
+//! 4 Lightning Bolt
+//! 2 Lightning Bolt (M10) 146
+//! 1x Fire // Ice (MH2)
+//! ```
+//!
+//! Empty lines, comments starting with `//` or `#`, and section headers such as
+//! `Deck` or `Sideboard` are skipped. Cards after a `Sideboard` header, or on a
+//! line starting with `SB:`, are marked as being in the sideboard.
+
+use crate::card::{Card, CardIdentifier};
+
+/// A decklist, resolved into cards.
+///
+/// # Examples
+/// ```rust
+/// # use scryfall::deck::Deck;
+/// # fn main() -> scryfall::Result<()> {
+/// let deck = Deck::from_text(
+///     "4 Lightning Bolt\n\
+///      2 Goblin Guide (ZEN) 126\n\
+///      1 Not a card name\n\
+///      \n\
+///      Sideboard\n\
+///      3 Smash to Smithereens",
+/// )?;
+/// assert_eq!(deck.entries.len(), 3);
+/// assert_eq!(deck.entries[0].quantity, 4);
+/// assert_eq!(deck.entries[1].card.set.get(), "zen");
+/// assert!(deck.entries[2].sideboard);
+/// assert_eq!(deck.unresolved[0].line_number, 3);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct Deck {
+    /// The cards found in the decklist, in the order they were listed.
+    pub entries: Vec<DeckEntry>,
+
+    /// The lines that could not be parsed or did not match any card.
+    pub unresolved: Vec<UnresolvedLine>,
+}
+
+/// A card in a [`Deck`], together with the number of copies.
+#[derive(Clone, PartialEq, Debug)]
+pub struct DeckEntry {
+    /// The number of copies of the card.
+    pub quantity: usize,
+
+    /// The card itself.
+    pub card: Card,
+
+    /// Whether the card is in the sideboard.
+    pub sideboard: bool,
+}
+
+/// A line of a decklist that could not be turned into a [`DeckEntry`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct UnresolvedLine {
+    /// The number of the line in the decklist, starting at 1.
+    pub line_number: usize,
+
+    /// The text of the line.
+    pub text: String,
+
+    /// Why the line could not be resolved.
+    pub reason: UnresolvedReason,
+}
+
+/// The reason a line of a decklist could not be resolved.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum UnresolvedReason {
+    /// The line is not in the decklist format.
+    Unparsable,
+    /// No card matches the line.
+    NotFound,
+}
+
+impl Deck {
+    /// Parses a decklist and fetches all of its cards with as few requests
+    /// to the collection endpoint as possible. See the [module
+    /// documentation][self] for the supported format.
+    pub fn from_text(text: &str) -> crate::Result<Self> {
+        let mut parsed = Vec::new();
+        let mut unresolved = Vec::new();
+        let mut sideboard = false;
+
+        for (i, line) in text.lines().enumerate() {
+            let unresolved_line = move |reason| UnresolvedLine {
+                line_number: i + 1,
+                text: line.to_string(),
+                reason,
+            };
+            match parse_line(line) {
+                Line::Skip => {},
+                Line::Section { sideboard: sb } => sideboard = sb,
+                Line::Card {
+                    quantity,
+                    identifier,
+                    sideboard: sb,
+                } => parsed.push((quantity, identifier, sideboard || sb, unresolved_line)),
+                Line::Invalid => unresolved.push(unresolved_line(UnresolvedReason::Unparsable)),
+            }
+        }
+
+        let identifiers = parsed
+            .iter()
+            .map(|(_, identifier, ..)| identifier.clone())
+            .collect::<Vec<_>>();
+        let cards = Card::collection(&identifiers)?;
+
+        let mut entries = Vec::with_capacity(parsed.len());
+        for ((quantity, _, sideboard, unresolved_line), card) in parsed.into_iter().zip(cards) {
+            match card {
+                Some(card) => entries.push(DeckEntry {
+                    quantity,
+                    card,
+                    sideboard,
+                }),
+                None => unresolved.push(unresolved_line(UnresolvedReason::NotFound)),
+            }
+        }
+        unresolved.sort_by_key(|line| line.line_number);
+
+        Ok(Deck {
+            entries,
+            unresolved,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Line {
+    Skip,
+    Section {
+        sideboard: bool,
+    },
+    Card {
+        quantity: usize,
+        identifier: CardIdentifier,
+        sideboard: bool,
+    },
+    Invalid,
+}
+
+pub(crate) fn parse_line(line: &str) -> Line {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with("//") || line.starts_with('#') {
+        return Line::Skip;
+    }
+
+    let header = line.trim_end_matches(':').to_ascii_lowercase();
+    match header.as_str() {
+        "deck" | "main" | "maindeck" | "commander" | "companion" => {
+            return Line::Section { sideboard: false };
+        },
+        "sideboard" => return Line::Section { sideboard: true },
+        _ => {},
+    }
+
+    let (line, sideboard) = match line.strip_prefix("SB:") {
+        Some(rest) => (rest.trim_start(), true),
+        None => (line, false),
+    };
+
+    let (quantity, rest) = match line.split_once(char::is_whitespace) {
+        Some((quantity, rest)) => (quantity, rest.trim()),
+        None => return Line::Invalid,
+    };
+    let quantity = match quantity.trim_end_matches(['x', 'X']).parse() {
+        Ok(quantity) => quantity,
+        Err(_) => return Line::Invalid,
+    };
+    if rest.is_empty() {
+        return Line::Invalid;
+    }
+
+    Line::Card {
+        quantity,
+        identifier: parse_identifier(rest),
+        sideboard,
+    }
+}
+
+/// Parses `Name`, `Name (SET)` or `Name (SET) 123`.
+fn parse_identifier(text: &str) -> CardIdentifier {
+    let set_and_number = text.rfind(" (").and_then(|start| {
+        let (set, number) = text[start + 2..].split_once(')')?;
+        let number = number.trim();
+        let valid_set = !set.is_empty() && set.chars().all(|c| c.is_ascii_alphanumeric());
+        let valid_number = !number.contains(char::is_whitespace);
+        if valid_set && valid_number {
+            Some((text[..start].trim(), set.to_ascii_lowercase(), number))
+        } else {
+            None
+        }
+    });
+
+    match set_and_number {
+        Some((name, set, "")) => CardIdentifier::NameSet {
+            name: name.to_string(),
+            set,
+        },
+        Some((_, set, number)) => CardIdentifier::CollectorNumberSet {
+            collector_number: number.to_string(),
+            set,
+        },
+        None => CardIdentifier::Name(text.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(quantity: usize, identifier: CardIdentifier, sideboard: bool) -> Line {
+        Line::Card {
+            quantity,
+            identifier,
+            sideboard,
+        }
+    }
+
+    #[test]
+    fn parse_lines() {
+        let name = |name: &str| CardIdentifier::Name(name.to_string());
+        let matrix = vec![
+            ("4 Lightning Bolt", card(4, name("Lightning Bolt"), false)),
+            ("  10x Mountain  ", card(10, name("Mountain"), false)),
+            ("1 Fire // Ice", card(1, name("Fire // Ice"), false)),
+            ("SB: 2 Duress", card(2, name("Duress"), true)),
+            (
+                "2 Lightning Bolt (M10) 146",
+                card(
+                    2,
+                    CardIdentifier::CollectorNumberSet {
+                        collector_number: "146".to_string(),
+                        set: "m10".to_string(),
+                    },
+                    false,
+                ),
+            ),
+            (
+                "1 Goblin Guide (ZEN)",
+                card(
+                    1,
+                    CardIdentifier::NameSet {
+                        name: "Goblin Guide".to_string(),
+                        set: "zen".to_string(),
+                    },
+                    false,
+                ),
+            ),
+            (
+                "1 Who // What // When // Where // Why",
+                card(1, name("Who // What // When // Where // Why"), false),
+            ),
+            ("", Line::Skip),
+            ("// Burn", Line::Skip),
+            ("Deck", Line::Section { sideboard: false }),
+            ("Sideboard:", Line::Section { sideboard: true }),
+            ("Lightning Bolt", Line::Invalid),
+            ("four Lightning Bolt", Line::Invalid),
+            ("4", Line::Invalid),
+        ];
+
+        for (line, expected) in matrix {
+            assert_eq!(parse_line(line), expected, "{:?}", line);
+        }
+    }
+}
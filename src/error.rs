@@ -18,6 +18,19 @@ pub enum Error {
     #[error("Error deserializing json: {0}")]
     JsonError(#[from] SerdeError),
 
+    /// Couldn't parse the json of a response from scryfall. Like
+    /// [`JsonError`][Error::JsonError], this should never occur, and usually
+    /// means scryfall added something this crate doesn't know about yet.
+    #[error("Error deserializing json from {url}: {error}\n\tbody: {body}")]
+    ResponseJsonError {
+        /// The error returned by `serde_json`.
+        error: SerdeError,
+        /// The URL of the request.
+        url: String,
+        /// The start of the response body, for debugging.
+        body: String,
+    },
+
     /// Couldn't write URL query params.
     #[error("Error writing URL query: {0}")]
     UrlEncodedError(#[from] serde_urlencoded::ser::Error),
@@ -30,6 +43,12 @@ pub enum Error {
     #[error("Error making request: {0}")]
     UreqError(Box<UreqError>, String),
 
+    /// Something went wrong when making an async HTTP request. Requires the
+    /// `async` feature.
+    #[cfg(feature = "async")]
+    #[error("Error making request: {0}")]
+    ReqwestError(Box<reqwest::Error>, String),
+
     /// Scryfall error. Please refer to the [official docs](https://scryfall.com/docs/api/errors).
     #[error("Scryfall error: {0}")]
     ScryfallError(ScryfallError),
@@ -47,6 +66,61 @@ pub enum Error {
     Other(String),
 }
 
+impl Error {
+    /// The HTTP status code of the response that caused this error, if it was
+    /// caused by an error response.
+    ///
+    /// To tell apart different errors with the same status, check the
+    /// [`code`][ScryfallError::code] and
+    /// [`error_type`][ScryfallError::error_type] of a
+    /// [`ScryfallError`][Error::ScryfallError].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::{Card, Error};
+    /// match Card::named("Not a card name") {
+    ///     Err(Error::ScryfallError(e)) => assert_eq!(e.code, "not_found"),
+    ///     other => panic!("{:?}", other),
+    /// }
+    /// let error = Card::named("Not a card name").unwrap_err();
+    /// assert_eq!(error.http_status(), Some(404));
+    /// ```
+    pub fn http_status(&self) -> Option<u16> {
+        match self {
+            Error::ScryfallError(error) => Some(error.status),
+            Error::HttpError(status) => Some(status.as_u16()),
+            Error::UreqError(error, _) => match error.as_ref() {
+                UreqError::Status(status, _) => Some(*status),
+                UreqError::Transport(_) => None,
+            },
+            #[cfg(feature = "async")]
+            Error::ReqwestError(error, _) => error.status().map(|status| status.as_u16()),
+            _ => None,
+        }
+    }
+
+    /// The URL of the request that caused this error, if it was caused by a
+    /// request and the URL is known.
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            Error::ResponseJsonError { url, .. } | Error::UreqError(_, url) => Some(url),
+            #[cfg(feature = "async")]
+            Error::ReqwestError(_, url) => Some(url),
+            _ => None,
+        }
+    }
+
+    /// Whether this error means that a fuzzy name matched too many cards for
+    /// Scryfall to pick one. To get the possible names instead, see
+    /// [`Card::named_fuzzy_candidates`][crate::Card::named_fuzzy_candidates].
+    pub fn is_ambiguous(&self) -> bool {
+        match self {
+            Error::ScryfallError(error) => error.error_type.as_deref() == Some("ambiguous"),
+            _ => false,
+        }
+    }
+}
+
 impl From<SerdeError> for Box<Error> {
     fn from(err: SerdeError) -> Self {
         Box::new(err.into())
@@ -108,3 +182,41 @@ impl fmt::Display for ScryfallError {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_scryfall_error() {
+        let json = r#"{
+            "object": "error",
+            "code": "not_found",
+            "status": 404,
+            "type": "ambiguous",
+            "details": "Too many cards match ambiguous name “jace”. Add more words to refine your search."
+        }"#;
+        let error = serde_json::from_str::<ScryfallError>(json).unwrap();
+        assert_eq!(error.code, "not_found");
+        assert_eq!(error.error_type.as_deref(), Some("ambiguous"));
+        assert!(error.warnings.is_empty());
+
+        let error = Error::ScryfallError(error);
+        assert_eq!(error.http_status(), Some(404));
+        assert!(error.is_ambiguous());
+        assert_eq!(Error::Other(String::new()).http_status(), None);
+        assert!(!Error::Other(String::new()).is_ambiguous());
+        assert_eq!(error.url(), None);
+    }
+
+    #[test]
+    fn response_json_error() {
+        let error = Error::ResponseJsonError {
+            error: serde_json::from_str::<ScryfallError>("{}").unwrap_err(),
+            url: "https://api.scryfall.com/cards/random".to_string(),
+            body: "{}".to_string(),
+        };
+        assert_eq!(error.url(), Some("https://api.scryfall.com/cards/random"));
+        assert!(error.to_string().contains("body: {}"));
+    }
+}
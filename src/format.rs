@@ -3,6 +3,8 @@ use std::fmt;
 
 use serde::{Deserialize, Serialize};
 
+use crate::util::from_str_by_display;
+
 #[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
 #[serde(rename_all = "lowercase")]
 #[allow(missing_docs)]
@@ -51,3 +53,10 @@ impl fmt::Display for Format {
         )
     }
 }
+
+from_str_by_display! {
+    Format, "format", [
+        Standard, Modern, Legacy, Vintage, Commander, Future, Pauper, Pioneer, Penny, Duel,
+        OldSchool, Historic, Gladiator, Brawl, Premodern,
+    ]
+}
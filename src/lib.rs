@@ -34,7 +34,7 @@
 //! For example, one could fetch all available card names.
 //! ```rust,no_run
 //! use scryfall::catalog::Catalog;
-//! assert!(Catalog::card_names().unwrap().data.len() > 0)
+//! assert!(Catalog::card_names().unwrap().len() > 0)
 //! ```
 //!
 //! ## Advanced Search
@@ -43,15 +43,25 @@
 //! For this the [`search`] module provides a type safe api
 //! to interact and query the search engine. For advanced features like
 //! sorting and collation, see [`search::advanced`].
+//!
+//! ## Async
+//! With the `async` feature, cards can also be fetched without blocking, from
+//! a [Tokio](https://tokio.rs) runtime, with `Card::named_async` and
+//! `Card::search_async`. Lists can be fetched page by page as a `Stream`
+//! with `Uri::fetch_pages_async`.
 pub mod bulk;
 pub mod card;
 pub mod catalog;
+pub mod client;
+pub mod deck;
 pub mod error;
 pub mod format;
 pub mod list;
+pub mod migration;
 pub mod ruling;
 pub mod search;
 pub mod set;
+pub mod symbology;
 pub mod uri;
 mod util;
 
@@ -64,6 +74,7 @@ pub use catalog::Catalog;
 pub use error::Error;
 pub use ruling::Ruling;
 pub use set::Set;
+pub use util::Uuid;
 
 #[cfg(test)]
 mod tests {
@@ -86,6 +97,40 @@ mod tests {
         assert_eq!(new_instance, instance)
     }
 
+    #[test]
+    fn enums_from_str_round_trip() {
+        use crate::card::{BorderColor, CardType, Color, Finish, Frame, FrameEffect, Game, Rarity};
+        use crate::format::Format;
+        use crate::set::SetType;
+
+        fn round_trip<T>(variants: &[T])
+        where
+            T: std::str::FromStr<Err = crate::Error> + ToString + PartialEq + std::fmt::Debug,
+        {
+            for variant in variants {
+                let s = variant.to_string();
+                assert_eq!(&s.parse::<T>().unwrap(), variant);
+                assert_eq!(&s.to_uppercase().parse::<T>().unwrap(), variant);
+                assert_eq!(&s.to_lowercase().parse::<T>().unwrap(), variant);
+            }
+            assert!("not a real value".parse::<T>().is_err());
+        }
+
+        round_trip(Format::VARIANTS);
+        round_trip(Rarity::VARIANTS);
+        round_trip(SetType::VARIANTS);
+        round_trip(BorderColor::VARIANTS);
+        round_trip(Finish::VARIANTS);
+        round_trip(Frame::VARIANTS);
+        round_trip(FrameEffect::VARIANTS);
+        round_trip(Currency::VARIANTS);
+        round_trip(Game::VARIANTS);
+        round_trip(Color::VARIANTS);
+        round_trip(CardType::VARIANTS);
+        assert_eq!("commander".parse::<Format>().unwrap(), Format::Commander);
+        assert_eq!("u".parse::<Color>().unwrap(), Color::Blue);
+    }
+
     #[test]
     #[ignore]
     fn all_sets() {
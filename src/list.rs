@@ -6,12 +6,17 @@
 //! of a `List`. If the list is paginated, the `ListIter` will request each page
 //! lazily.
 
+use std::collections::HashSet;
+use std::hash::Hash;
 use std::vec;
 
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+use crate::card::Card;
+use crate::client::ScryfallClient;
 use crate::uri::Uri;
+use crate::util::Uuid;
 
 /// A List object represents a requested sequence of other objects (Cards, Sets,
 /// etc). List objects may be paginated, and also include information about
@@ -70,6 +75,8 @@ impl<T: DeserializeOwned> IntoIterator for List<T> {
             page_num: 1,
             total: self.total_cards,
             remaining: self.total_cards,
+            page_limit: None,
+            client: None,
         }
     }
 }
@@ -88,6 +95,8 @@ pub struct ListIter<T> {
     page_num: usize,
     total: Option<usize>,
     remaining: Option<usize>,
+    page_limit: Option<usize>,
+    client: Option<ScryfallClient>,
 }
 
 impl<T> ListIter<T> {
@@ -107,6 +116,41 @@ impl<T> ListIter<T> {
     pub fn into_inner(self) -> vec::IntoIter<T> {
         self.inner
     }
+
+    /// Stops this iterator from requesting more than `pages` pages in total,
+    /// counting the page it already holds. Once the last allowed page has
+    /// been used up, the iterator ends, even though Scryfall may report that
+    /// the list [`has_more`][List::has_more] items.
+    ///
+    /// This only affects iteration. [`next_page`][ListIter::next_page] can
+    /// still be called to load the following page on demand, and the
+    /// returned iterator keeps the same limit.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use scryfall::Card;
+    /// let cards = Card::search("t:creature")
+    ///     .unwrap()
+    ///     .page_limit(2)
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .unwrap();
+    /// assert_eq!(cards.len(), 2 * 175);
+    /// ```
+    pub fn page_limit(mut self, pages: usize) -> Self {
+        self.page_limit = Some(pages);
+        self
+    }
+
+    /// Whether the page limit stops this iterator from fetching more pages.
+    fn at_page_limit(&self) -> bool {
+        self.page_limit.is_some_and(|limit| self.page_num >= limit)
+    }
+
+    /// Makes this iterator request further pages with `client`.
+    pub(crate) fn with_client(mut self, client: &ScryfallClient) -> Self {
+        self.client = Some(client.clone());
+        self
+    }
 }
 
 impl<T: DeserializeOwned> ListIter<T> {
@@ -131,9 +175,13 @@ impl<T: DeserializeOwned> ListIter<T> {
     /// ```
     pub fn next_page(&self) -> crate::Result<Option<Self>> {
         if let Some(uri) = self.next_uri.as_ref() {
-            let mut new_iter = uri.fetch_iter()?;
+            let mut new_iter = match &self.client {
+                Some(client) => uri.fetch_iter_with(client)?,
+                None => uri.fetch_iter()?,
+            };
             new_iter.remaining = self.remaining.map(|r| r - self.inner.len());
             new_iter.page_num = self.page_num + 1;
+            new_iter.page_limit = self.page_limit;
 
             // The new total should be the same as the old total.
             debug_assert_eq!(self.total, new_iter.total);
@@ -143,6 +191,39 @@ impl<T: DeserializeOwned> ListIter<T> {
             Ok(None)
         }
     }
+
+    /// Collects every item of this list and sorts them by `key`, for sorting
+    /// locally when Scryfall's [sort order][crate::search::advanced::SortOrder]
+    /// can't be used.
+    ///
+    /// This is eager: all the remaining pages are fetched and held in memory
+    /// before the first item is yielded. The first error stops the collection
+    /// and is returned. The sort is stable, so items with equal keys keep the
+    /// order Scryfall returned them in.
+    ///
+    /// To sort the results of several searches together, collect them into a
+    /// `Vec` and sort that instead.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use scryfall::search::prelude::*;
+    /// let by_power = type_line("goblin")
+    ///     .and(power(gte(4)))
+    ///     .search()
+    ///     .unwrap()
+    ///     .sorted_by_key(|card| card.power.as_deref().and_then(|p| p.parse::<u32>().ok()))
+    ///     .unwrap()
+    ///     .collect::<Vec<_>>();
+    /// assert!(by_power.windows(2).all(|pair| pair[0].power <= pair[1].power));
+    /// ```
+    pub fn sorted_by_key<K: Ord>(
+        self,
+        key: impl FnMut(&T) -> K,
+    ) -> crate::Result<vec::IntoIter<T>> {
+        let mut items = self.collect::<crate::Result<Vec<_>>>()?;
+        items.sort_by_key(key);
+        Ok(items.into_iter())
+    }
 }
 
 impl<T: DeserializeOwned> Iterator for ListIter<T> {
@@ -154,6 +235,7 @@ impl<T: DeserializeOwned> Iterator for ListIter<T> {
                 self.remaining = self.remaining.map(|r| r - 1);
                 Some(Ok(next))
             },
+            None if self.at_page_limit() => None,
             None => match self.next_page() {
                 Ok(Some(new_iter)) => {
                     *self = new_iter;
@@ -170,8 +252,15 @@ impl<T: DeserializeOwned> Iterator for ListIter<T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        if let Some(len) = self.remaining {
+        if self.at_page_limit() {
+            let len = self.inner.len();
             (len, Some(len))
+        } else if let Some(len) = self.remaining {
+            if self.page_limit.is_some() {
+                (self.inner.len(), Some(len))
+            } else {
+                (len, Some(len))
+            }
         } else {
             let len = self.inner.len();
             (
@@ -186,6 +275,119 @@ impl<T: DeserializeOwned> Iterator for ListIter<T> {
     }
 }
 
+impl ListIter<Card> {
+    /// Collects and sorts these cards by [`cmc`][Card::cmc], lowest first.
+    /// Split cards count the mana costs of both halves. Like
+    /// [`sorted_by_key`][ListIter::sorted_by_key], this fetches every page
+    /// first.
+    pub fn sorted_by_cmc(self) -> crate::Result<vec::IntoIter<Card>> {
+        let mut cards = self.collect::<crate::Result<Vec<_>>>()?;
+        cards.sort_by(|a, b| a.cmc.total_cmp(&b.cmc));
+        Ok(cards.into_iter())
+    }
+
+    /// Collects and sorts these cards by [`name`][Card::name], ignoring case.
+    /// Like [`sorted_by_key`][ListIter::sorted_by_key], this fetches every
+    /// page first.
+    pub fn sorted_by_name(self) -> crate::Result<vec::IntoIter<Card>> {
+        self.sorted_by_key(|card| card.name.to_lowercase())
+    }
+
+    /// Skips cards with the same oracle ID as a card already yielded, keeping
+    /// only one printing of each card. This mimics
+    /// [`UniqueStrategy::Cards`][crate::search::advanced::UniqueStrategy::Cards],
+    /// but can also be used on results combined from several searches with
+    /// [`UniqueBy::new`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use scryfall::search::prelude::*;
+    /// let bolts = SearchOptions::new()
+    ///     .query(exact("Lightning Bolt"))
+    ///     .unique(UniqueStrategy::Prints)
+    ///     .search()
+    ///     .unwrap()
+    ///     .unique_by_oracle_id()
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(bolts.len(), 1);
+    /// ```
+    pub fn unique_by_oracle_id(self) -> UniqueCards {
+        UniqueBy::new(self, |card| Some(card.oracle_id))
+    }
+
+    /// Skips cards with the same illustration ID as a card already yielded,
+    /// keeping only one printing of each artwork. Cards without an
+    /// illustration ID are never skipped. This mimics
+    /// [`UniqueStrategy::Art`][crate::search::advanced::UniqueStrategy::Art].
+    pub fn unique_by_illustration_id(self) -> UniqueCards {
+        UniqueBy::new(self, |card| card.illustration_id)
+    }
+}
+
+/// The iterator returned by [`ListIter::unique_by_oracle_id`] and
+/// [`ListIter::unique_by_illustration_id`].
+pub type UniqueCards = UniqueBy<ListIter<Card>, fn(&Card) -> Option<Uuid>, Uuid>;
+
+/// An iterator that skips items whose key has already been seen, created by
+/// methods such as [`ListIter::unique_by_oracle_id`]. Items without a key and
+/// errors are always yielded.
+///
+/// # Examples
+/// ```rust,no_run
+/// # use scryfall::list::UniqueBy;
+/// # use scryfall::search::prelude::*;
+/// let goblins = type_line("goblin").search().unwrap();
+/// let red_cards = color("r").search().unwrap();
+/// let names = UniqueBy::new(goblins.chain(red_cards), |card: &scryfall::Card| {
+///     Some(card.oracle_id)
+/// })
+/// .map(|card| card.unwrap().name)
+/// .collect::<Vec<_>>();
+/// ```
+#[derive(Debug, Clone)]
+pub struct UniqueBy<I, F, K> {
+    iter: I,
+    key: F,
+    seen: HashSet<K>,
+}
+
+impl<I, F, K> UniqueBy<I, F, K> {
+    /// Creates an iterator yielding the items of `iter`, skipping those with
+    /// a `key` that has already been seen.
+    pub fn new(iter: I, key: F) -> Self {
+        UniqueBy {
+            iter,
+            key,
+            seen: HashSet::new(),
+        }
+    }
+}
+
+impl<I, T, F, K> Iterator for UniqueBy<I, F, K>
+where
+    I: Iterator<Item = crate::Result<T>>,
+    F: FnMut(&T) -> Option<K>,
+    K: Eq + Hash,
+{
+    type Item = crate::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for item in &mut self.iter {
+            if let Some(key) = item.as_ref().ok().and_then(&mut self.key) {
+                if !self.seen.insert(key) {
+                    continue;
+                }
+            }
+            return Some(item);
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
 /// An iterator over the pages of a list. Before returning each page, the next
 /// page is requested.
 pub struct PageIter<T> {
@@ -217,3 +419,104 @@ impl<T: DeserializeOwned> Iterator for PageIter<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::*;
+    use crate::error::Error;
+
+    #[test]
+    fn unique_by_skips_seen_keys() {
+        let items = vec![
+            Ok((1, "a")),
+            Ok((2, "b")),
+            Ok((1, "c")),
+            Err(Error::Other("page failed".to_string())),
+            Ok((0, "d")),
+            Ok((0, "e")),
+            Ok((2, "f")),
+        ];
+        let unique = UniqueBy::new(items.into_iter(), |&(id, _): &(u32, &str)| {
+            (id != 0).then_some(id)
+        })
+        .map(|item| item.map(|(_, name)| name).map_err(|_| "error"))
+        .collect::<Vec<_>>();
+
+        assert_eq!(unique, [Ok("a"), Ok("b"), Err("error"), Ok("d"), Ok("e")]);
+    }
+
+    #[test]
+    fn sorts_cards_locally() {
+        let card = |name: &str, cmc: f32, layout: &str| {
+            crate::card::tests::card_with(|json| {
+                json["name"] = name.into();
+                json["cmc"] = cmc.into();
+                json["layout"] = layout.into();
+            })
+        };
+        let list = || List {
+            data: vec![
+                card("Fire // Ice", 4.0, "split"),
+                card("lightning Bolt", 1.0, "normal"),
+                card("Ancestral Recall", 1.0, "normal"),
+                card("Black Lotus", 0.0, "normal"),
+                card("Counterspell", 2.0, "normal"),
+            ],
+            has_more: false,
+            next_page: None,
+            total_cards: Some(5),
+            warnings: None,
+        };
+        let names = |cards: vec::IntoIter<Card>| cards.map(|c| c.name).collect::<Vec<_>>();
+
+        assert_eq!(
+            names(list().into_iter().sorted_by_cmc().unwrap()),
+            [
+                "Black Lotus",
+                "lightning Bolt",
+                "Ancestral Recall",
+                "Counterspell",
+                "Fire // Ice"
+            ]
+        );
+        assert_eq!(
+            names(list().into_iter().sorted_by_name().unwrap()),
+            [
+                "Ancestral Recall",
+                "Black Lotus",
+                "Counterspell",
+                "Fire // Ice",
+                "lightning Bolt"
+            ]
+        );
+        assert_eq!(
+            names(
+                list()
+                    .into_iter()
+                    .sorted_by_key(|c| std::cmp::Reverse(c.name.len()))
+                    .unwrap()
+            )[0],
+            "Ancestral Recall"
+        );
+    }
+
+    #[test]
+    fn page_limit_stops_before_fetching() {
+        let list = List {
+            data: vec![1, 2, 3],
+            has_more: true,
+            next_page: Some(
+                Uri::try_from("https://api.scryfall.com/cards/search?q=c%3Aw&page=2").unwrap(),
+            ),
+            total_cards: Some(10),
+            warnings: None,
+        };
+        let mut iter = list.into_iter().page_limit(1);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        let items = iter.by_ref().map(Result::unwrap).collect::<Vec<u32>>();
+        assert_eq!(items, [1, 2, 3]);
+        assert!(iter.next().is_none());
+    }
+}
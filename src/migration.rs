@@ -0,0 +1,120 @@
+//! Migrations record changes to the ids of cards in Scryfall's database, such
+//! as when two duplicate cards are merged, or a card that doesn't exist is
+//! deleted. Anyone storing Scryfall ids can use them to keep those ids up to
+//! date.
+//!
+//! For more information, refer to the [official docs](https://scryfall.com/docs/api/migrations).
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::list::ListIter;
+use crate::uri::Uri;
+use crate::util::{Uuid, MIGRATIONS_URL};
+
+/// A change to the id of a card in Scryfall's database.
+///
+/// For more information, refer to the [official docs](https://scryfall.com/docs/api/migrations).
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Migration {
+    /// A unique ID for this migration.
+    pub id: Uuid,
+
+    /// A link to this migration on Scryfall's API.
+    pub uri: Uri<Migration>,
+
+    /// The date this migration was performed.
+    pub performed_at: NaiveDate,
+
+    /// What happened to the card with the old id.
+    pub migration_strategy: MigrationStrategy,
+
+    /// The id of the card that was changed.
+    pub old_scryfall_id: Uuid,
+
+    /// The id the card was merged into, if it was merged.
+    pub new_scryfall_id: Option<Uuid>,
+
+    /// A note left by the Scryfall team about this migration, if any.
+    pub note: Option<String>,
+
+    /// Additional data about the migration, if any.
+    pub metadata: Option<serde_json::Value>,
+}
+
+/// What a [`Migration`] did to a card.
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrationStrategy {
+    /// The card was merged into another card. Its old id should be replaced
+    /// with the [`new_scryfall_id`][Migration::new_scryfall_id].
+    Merge,
+    /// The card was removed from Scryfall's database, and there is no
+    /// replacement for it.
+    Delete,
+}
+
+impl Migration {
+    /// Returns an iterator over all migrations, starting with the most
+    /// recent.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::migration::{Migration, MigrationStrategy};
+    /// let migration = Migration::all().unwrap().next().unwrap().unwrap();
+    /// if migration.migration_strategy == MigrationStrategy::Merge {
+    ///     assert!(migration.new_scryfall_id.is_some());
+    /// }
+    /// ```
+    pub fn all() -> crate::Result<ListIter<Migration>> {
+        Uri::from(MIGRATIONS_URL.clone()).fetch_iter()
+    }
+
+    /// Returns the migration with the given id.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::migration::Migration;
+    /// let latest = Migration::all().unwrap().next().unwrap().unwrap();
+    /// assert_eq!(Migration::get(latest.id).unwrap(), latest);
+    /// ```
+    pub fn get(id: Uuid) -> crate::Result<Migration> {
+        Uri::from(MIGRATIONS_URL.join(&id.to_string())?).fetch()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_migration() {
+        let json = r#"{
+            "object": "migration",
+            "id": "0a3e6b10-bd6b-4f02-8a6c-2c0da9fba0e0",
+            "uri": "https://api.scryfall.com/migrations/0a3e6b10-bd6b-4f02-8a6c-2c0da9fba0e0",
+            "performed_at": "2021-01-01",
+            "migration_strategy": "merge",
+            "old_scryfall_id": "5f70ba4c-3a7c-4a7c-91ba-b4b3e1b4c2f0",
+            "new_scryfall_id": "87b1e9df-e7a3-4b0a-8f76-c4c5a0d6a8f5",
+            "note": "Duplicate card",
+            "metadata": null
+        }"#;
+        let migration: Migration = serde_json::from_str(json).unwrap();
+        assert_eq!(migration.migration_strategy, MigrationStrategy::Merge);
+        assert_eq!(
+            migration.new_scryfall_id,
+            Some("87b1e9df-e7a3-4b0a-8f76-c4c5a0d6a8f5".parse().unwrap())
+        );
+        assert_eq!(migration.note.as_deref(), Some("Duplicate card"));
+
+        let deleted: Migration = serde_json::from_str(
+            &json
+                .replace("\"merge\"", "\"delete\"")
+                .replace("\"87b1e9df-e7a3-4b0a-8f76-c4c5a0d6a8f5\"", "null"),
+        )
+        .unwrap();
+        assert_eq!(deleted.migration_strategy, MigrationStrategy::Delete);
+        assert_eq!(deleted.new_scryfall_id, None);
+    }
+}
@@ -8,13 +8,21 @@
 //! either to provide additional context for the card, or explain how the card
 //! works in an unofficial format (such as Duel Commander).
 
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
+use crate::card::Card;
 use crate::list::ListIter;
 use crate::uri::Uri;
-use crate::util::{API_RULING, CARDS_URL};
+use crate::util::{Uuid, API_RULING, CARDS_URL};
+
+/// The maximum number of rulings requests [`Ruling::for_cards`] has in flight
+/// at once.
+const FOR_CARDS_MAX_CONCURRENT_REQUESTS: usize = 4;
 
 /// Rulings represent Oracle rulings, Wizards of the Coast set release notes, or
 /// Scryfall notes for a particular card.
@@ -150,6 +158,29 @@ impl Ruling {
         .fetch_iter()
     }
 
+    /// Returns a List of rulings for the cards with the given Oracle ID.
+    ///
+    /// Scryfall has no rulings endpoint for Oracle IDs, so this first looks up
+    /// a card with the Oracle ID and then requests its rulings.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// use scryfall::ruling::Ruling;
+    /// let oracle_id = Card::named("Blood Moon").unwrap().oracle_id;
+    /// assert!(
+    ///     Ruling::oracle_id(oracle_id)
+    ///         .unwrap()
+    ///         .map(Result::unwrap)
+    ///         .all(|r| r.oracle_id == oracle_id)
+    /// );
+    /// ```
+    pub fn oracle_id(id: Uuid) -> crate::Result<ListIter<Self>> {
+        Card::search_random(format!("oracleid:{}", id))?
+            .rulings_uri
+            .fetch_iter()
+    }
+
     /// Returns a List of rulings for a card with the given Scryfall ID.
     ///
     /// # Examples
@@ -165,4 +196,82 @@ impl Ruling {
     pub fn uuid(id: Uuid) -> crate::Result<ListIter<Self>> {
         Uri::from(CARDS_URL.join(&format!("{}/", id))?.join(API_RULING)?).fetch_iter()
     }
+
+    /// Returns the rulings for each of the cards with the given Scryfall IDs,
+    /// keyed by ID.
+    ///
+    /// Scryfall has no endpoint for the rulings of many cards, so this makes
+    /// one request per card. Up to 4 requests are in flight at once, and they
+    /// still respect the [minimum delay][crate::uri::set_min_request_delay]
+    /// between requests. If any request fails, the first error is returned.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// use scryfall::ruling::Ruling;
+    /// let ids = ["Blood Moon", "Humility"]
+    ///     .iter()
+    ///     .map(|name| Card::named(name).map(|card| card.id))
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .unwrap();
+    /// let rulings = Ruling::for_cards(&ids).unwrap();
+    /// assert_eq!(rulings.len(), 2);
+    /// assert!(rulings.values().all(|rulings| !rulings.is_empty()));
+    /// ```
+    pub fn for_cards(ids: &[Uuid]) -> crate::Result<HashMap<Uuid, Vec<Self>>> {
+        let mut seen = HashSet::new();
+        let ids = ids
+            .iter()
+            .copied()
+            .filter(|id| seen.insert(*id))
+            .collect::<Vec<_>>();
+        let next = AtomicUsize::new(0);
+        let workers = ids.len().min(FOR_CARDS_MAX_CONCURRENT_REQUESTS);
+
+        thread::scope(|scope| {
+            let handles = (0..workers)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut rulings = Vec::new();
+                        while let Some(&id) = ids.get(next.fetch_add(1, Ordering::Relaxed)) {
+                            match Ruling::uuid(id).and_then(Iterator::collect) {
+                                Ok(card_rulings) => rulings.push((id, card_rulings)),
+                                Err(e) => {
+                                    // Stop the other workers from taking more IDs.
+                                    next.store(ids.len(), Ordering::Relaxed);
+                                    return Err(e);
+                                },
+                            }
+                        }
+                        Ok(rulings)
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let mut result = HashMap::with_capacity(ids.len());
+            let mut error = None;
+            for handle in handles {
+                match handle.join().unwrap() {
+                    Ok(rulings) => result.extend(rulings),
+                    Err(e) => {
+                        error.get_or_insert(e);
+                    },
+                }
+            }
+            match error {
+                Some(e) => Err(e),
+                None => Ok(result),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn for_no_cards() {
+        assert!(Ruling::for_cards(&[]).unwrap().is_empty());
+    }
 }
@@ -24,7 +24,7 @@
 //! complex queries to Scryfall.
 use url::Url;
 
-use crate::list::ListIter;
+use crate::list::{List, ListIter};
 use crate::Card;
 
 pub mod advanced;
@@ -46,6 +46,23 @@ pub trait Search {
     /// Write this search as the query for the given `Url`.
     fn write_query(&self, url: &mut Url) -> crate::Result<()>;
 
+    /// Write this search as the query for a `cards/random` request. That
+    /// endpoint only accepts the search query itself, so implementors with
+    /// other options, such as [`SearchOptions`][self::advanced::SearchOptions],
+    /// leave them out. Defaults to [`write_query`][Search::write_query].
+    fn write_random_query(&self, url: &mut Url) -> crate::Result<()> {
+        self.write_query(url)
+    }
+
+    /// The most pages [`Card::search`] and [`Card::search_all`] should fetch
+    /// for this search, counting the first one. Defaults to `None`, which
+    /// fetches every page. See
+    /// [`SearchOptions::page_limit`][self::advanced::SearchOptions::page_limit].
+    fn max_pages(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns the query string this search writes, for use in tests.
     #[cfg(test)]
     fn query_string(&self) -> crate::Result<String> {
         let mut url = Url::parse("http://localhost")?;
@@ -58,14 +75,37 @@ pub trait Search {
         Card::search(self)
     }
 
+    /// Convenience method for passing this object to [`Card::search_page`].
+    fn search_page(&self) -> crate::Result<List<Card>> {
+        Card::search_page(self)
+    }
+
     /// Convenience method for passing this object to [`Card::search_all`].
     fn search_all(&self) -> crate::Result<Vec<Card>> {
         Card::search_all(self)
     }
 
-    /// Convenience method for passing this object to [`Card::search_random`].
+    /// Returns at most `max` cards that match this search, as a `Vec`.
+    ///
+    /// Unlike [`search_all`][Search::search_all], further pages are only
+    /// requested until `max` cards have been collected.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::search::Search;
+    /// let goblins = "t:goblin".search_all_capped(200).unwrap();
+    /// assert_eq!(goblins.len(), 200);
+    /// ```
+    fn search_all_capped(&self, max: usize) -> crate::Result<Vec<Card>> {
+        if max == 0 {
+            return Ok(Vec::new());
+        }
+        Card::search(self)?.take(max).collect()
+    }
+
+    /// Convenience method for passing this object to [`Card::random_with`].
     fn random(&self) -> crate::Result<Card> {
-        Card::search_random(self)
+        Card::random_with(self)
     }
 }
 
@@ -73,12 +113,28 @@ impl<T: Search + ?Sized> Search for &T {
     fn write_query(&self, url: &mut Url) -> crate::Result<()> {
         <T as Search>::write_query(*self, url)
     }
+
+    fn write_random_query(&self, url: &mut Url) -> crate::Result<()> {
+        <T as Search>::write_random_query(*self, url)
+    }
+
+    fn max_pages(&self) -> Option<usize> {
+        <T as Search>::max_pages(*self)
+    }
 }
 
 impl<T: Search + ?Sized> Search for &mut T {
     fn write_query(&self, url: &mut Url) -> crate::Result<()> {
         <T as Search>::write_query(*self, url)
     }
+
+    fn write_random_query(&self, url: &mut Url) -> crate::Result<()> {
+        <T as Search>::write_random_query(*self, url)
+    }
+
+    fn max_pages(&self) -> Option<usize> {
+        <T as Search>::max_pages(*self)
+    }
 }
 
 #[inline]
@@ -111,60 +167,18 @@ pub mod prelude {
     pub use super::param::compare::{eq, gt, gte, lt, lte, neq};
     pub use super::param::criteria::{CardIs, PrintingIs};
     pub use super::param::value::{
-        artist,
-        artist_count,
-        banned,
-        block,
-        border_color,
-        cheapest,
-        cmc,
-        collector_number,
-        color,
-        color_count,
-        color_identity,
-        color_identity_count,
-        cube,
-        date,
-        devotion,
-        eur,
-        flavor_text,
-        format,
-        frame,
-        full_oracle_text,
-        game,
-        illustration_count,
-        in_game,
-        in_language,
-        in_rarity,
-        in_set,
-        in_set_type,
-        keyword,
-        language,
-        loyalty,
-        mana,
-        name,
-        oracle_text,
-        paper_print_count,
-        paper_set_count,
-        pow_tou,
-        power,
-        print_count,
-        produces,
-        rarity,
-        restricted,
-        set,
-        set_count,
-        set_type,
-        tix,
-        toughness,
-        type_line,
-        usd,
-        usd_foil,
-        watermark,
-        year,
-        Devotion,
-        NumProperty,
-        Regex,
+        artist, artist_count, banned, block, border_color, cheapest, cmc, collector_number, color,
+        color_count, color_identity, color_identity_count, cube, date, devotion, eur, flavor_text,
+        format, frame, frame_effect, full_oracle_text, game, illustration, illustration_count,
+        in_game, in_language, in_rarity, in_set, in_set_type, keyword, keyword_lenient,
+        keyword_strict, language, loyalty, mana, name, oracle_text, paper_print_count,
+        paper_set_count, pow_tou, power, print_count, produces, rarity, restricted, set, set_count,
+        set_type, tix, toughness, type_line, usd, usd_foil, watermark, year, BorderColorValue,
+        CollectorNumber, CollectorNumberValue, ColorValue, CubeValue, Currency, CurrencyValue,
+        Date, DateValue, Devotion, DevotionValue, FormatValue, FrameEffectValue, FrameValue,
+        GameValue, IllustrationValue, LanguageValue, ManaValue, NumProperty,
+        NumericComparableValue, NumericValue, ParamValue, RarityValue, Regex, SetTypeValue,
+        SetValue, TextOrRegexValue, TextValue, WatermarkValue,
     };
     pub use super::param::{exact, Param};
     pub use super::query::{not, Query};
@@ -173,6 +187,8 @@ pub mod prelude {
 
 #[cfg(test)]
 mod tests {
+    use url::Url;
+
     use super::prelude::*;
     use crate::Card;
 
@@ -201,21 +217,19 @@ mod tests {
     fn random_works_with_search_options() {
         // `SearchOptions` can set more query params than the "cards/random" API method
         // accepts. Scryfall should ignore these and return a random card.
-        assert!(
-            SearchOptions::new()
-                .query(keyword("storm"))
-                .unique(UniqueStrategy::Art)
-                .sort(SortOrder::Usd, SortDirection::Ascending)
-                .extras(true)
-                .multilingual(true)
-                .variations(true)
-                .random()
-                .unwrap()
-                .oracle_text
-                .unwrap()
-                .to_lowercase()
-                .contains("storm")
-        );
+        assert!(SearchOptions::new()
+            .query(keyword("storm"))
+            .unique(UniqueStrategy::Art)
+            .sort(SortOrder::Usd, SortDirection::Ascending)
+            .extras(true)
+            .multilingual(true)
+            .variations(true)
+            .random()
+            .unwrap()
+            .oracle_text
+            .unwrap()
+            .to_lowercase()
+            .contains("storm"));
     }
 
     #[test]
@@ -254,12 +268,411 @@ mod tests {
 
         assert!(cards.len() >= 9, "Couldn't find the Power Nine from VMA.");
 
-        assert!(
-            cards
-                .into_iter()
-                .map(|c| c.unwrap())
-                .all(|c| c.rarity > Rarity::Mythic)
+        assert!(cards
+            .into_iter()
+            .map(|c| c.unwrap())
+            .all(|c| c.rarity > Rarity::Mythic));
+    }
+
+    #[test]
+    fn numeric_comparisons() {
+        let matrix = vec![
+            (power(gt(3)), "power>3"),
+            (power(eq(NumProperty::Toughness)), "power=toughness"),
+            (toughness(lte(1)), "toughness<=1"),
+            (toughness(gt(NumProperty::Power)), "toughness>power"),
+            (pow_tou(10), "powtou:10"),
+            (pow_tou(eq(NumProperty::Cmc)), "powtou=cmc"),
+            (loyalty(gte(5)), "loyalty>=5"),
+            (loyalty(lt(NumProperty::Cmc)), "loyalty<cmc"),
+            (cmc(neq(4)), "cmc!=4"),
+            (cmc(gt(NumProperty::Power)), "cmc>power"),
+        ];
+        for (query, expected) in matrix {
+            assert_eq!(query.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn devotion_symbols() {
+        use crate::card::Color;
+
+        let matrix = vec![
+            (Devotion::monocolor(Color::White, 3), "{W}{W}{W}"),
+            (Devotion::monocolor(Color::Green, 1), "{G}"),
+            (Devotion::hybrid(Color::Black, Color::Red, 2), "{B/R}{B/R}"),
+            (Devotion::hybrid(Color::Blue, Color::Blue, 2), "{U}{U}"),
+            (Devotion::monocolor(Color::Red, 0), "0"),
+        ];
+        for (devotion, expected) in matrix {
+            assert_eq!(devotion.to_string(), expected);
+        }
+        assert_eq!(
+            devotion(Devotion::monocolor(Color::White, 3)).to_string(),
+            "devotion:{W}{W}{W}"
+        );
+        assert_eq!(
+            devotion(gte(Devotion::monocolor(Color::White, 3))).to_string(),
+            "devotion>={W}{W}{W}"
+        );
+        assert_eq!(
+            devotion(eq(Devotion::hybrid(Color::Green, Color::White, 1))).to_string(),
+            "devotion={G/W}"
+        );
+    }
+
+    #[test]
+    fn produces_colors() {
+        use crate::card::Colors;
+
+        assert_eq!(produces(gte(Colors::AZORIUS)).to_string(), "produces>=WU");
+        assert_eq!(produces(Colors::colorless()).to_string(), "produces:C");
+    }
+
+    #[test]
+    fn lands_producing_white_and_blue() {
+        use crate::card::{Color, Colors};
+
+        let land = type_line("land")
+            .and(produces(gte(Colors::AZORIUS)))
+            .random()
+            .unwrap();
+        let produced = land.produced_mana.unwrap_or_default();
+        assert!(produced.contains(&Color::White), "{}", land.name);
+        assert!(produced.contains(&Color::Blue), "{}", land.name);
+    }
+
+    #[test]
+    fn watermark_values() {
+        use crate::card::Watermark;
+
+        assert_eq!(watermark(Watermark::Boros).to_string(), "watermark:boros");
+        assert_eq!(
+            watermark(Watermark::Other("dnd".to_string())).to_string(),
+            "watermark:\"dnd\""
+        );
+        assert_eq!(watermark("orzhov").to_string(), "watermark:\"orzhov\"");
+    }
+
+    #[test]
+    fn most_expensive_common() {
+        use crate::card::Rarity;
+
+        let query = rarity(Rarity::Common).and(cheapest(Currency::Usd));
+        assert_eq!(query.to_string(), "(rarity:common AND cheapest:usd)");
+
+        let card = SearchOptions::new()
+            .query(query)
+            .sort(SortOrder::Usd, SortDirection::Descending)
+            .unique(UniqueStrategy::Cards)
+            .search()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(card.rarity, Rarity::Common);
+        assert!(card.prices.usd.is_some());
+    }
+
+    #[test]
+    fn collector_number_suffixes() {
+        let matrix = vec![
+            (collector_number(123), "number:123"),
+            (collector_number(gt(50)), "number>50"),
+            (collector_number("123a"), "number:\"123a\""),
+            (collector_number("★"), "number:\"★\""),
+            (collector_number(CollectorNumber::from("1")), "number:1"),
+            (
+                collector_number(CollectorNumber::from("45p")),
+                "number:\"45p\"",
+            ),
+            (
+                collector_number(CollectorNumber::from("2s★")),
+                "number:\"2s★\"",
+            ),
+        ];
+
+        for (query, expected) in matrix {
+            assert_eq!(query.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn collector_numbers_in_a_set() {
+        assert_eq!(
+            set("war").and(collector_number(123)).to_string(),
+            "(set:\"war\" AND number:123)"
+        );
+        assert_eq!(
+            set("ktk").and(collector_number(gte(200))).to_string(),
+            "(set:\"ktk\" AND number>=200)"
+        );
+    }
+
+    #[test]
+    fn masterpiece_set_type() {
+        use crate::set::SetType;
+
+        assert_eq!(
+            set_type(SetType::Masterpiece).to_string(),
+            "settype:masterpiece"
+        );
+        let card = set_type(SetType::Masterpiece).random().unwrap();
+        let set = card.set_uri.fetch().unwrap();
+        assert_eq!(set.set_type, SetType::Masterpiece, "{}", card.name);
+    }
+
+    #[test]
+    fn prelude_has_every_helper() {
+        use crate::card::Color;
+
+        // Fails to compile if any of the helpers isn't in the prelude.
+        let queries = vec![
+            artist("a"),
+            artist_count(1),
+            banned("a"),
+            block("a"),
+            border_color("a"),
+            cheapest("a"),
+            cmc(1),
+            collector_number(1),
+            color("a"),
+            color_count(1),
+            color_identity("a"),
+            color_identity_count(1),
+            cube("a"),
+            date("a"),
+            devotion(Devotion::monocolor(Color::Red, 1)),
+            eur(1),
+            flavor_text("a"),
+            format("a"),
+            frame("a"),
+            frame_effect("a"),
+            full_oracle_text("a"),
+            game("a"),
+            illustration("a"),
+            illustration_count(1),
+            in_game("a"),
+            in_language("a"),
+            in_rarity("a"),
+            in_set("a"),
+            in_set_type("a"),
+            keyword("a"),
+            language("a"),
+            loyalty(1),
+            name("a"),
+            oracle_text("a"),
+            paper_print_count(1),
+            paper_set_count(1),
+            pow_tou(1),
+            power(1),
+            print_count(1),
+            produces("a"),
+            rarity("a"),
+            restricted("a"),
+            set("a"),
+            set_count(1),
+            set_type("a"),
+            tix(1),
+            toughness(1),
+            type_line("a"),
+            usd(1),
+            usd_foil(1),
+            watermark("a"),
+            year(1),
+            exact("a"),
+            not(name("a")),
+        ];
+        assert!(queries.iter().all(|query| !query.to_string().is_empty()));
+        let _: crate::Result<Query> = mana("{W}");
+        let _: fn(&str) -> crate::Result<Query> = keyword_strict;
+        let _: fn(&str) -> crate::Result<Query> = keyword_lenient;
+    }
+
+    #[test]
+    fn frames_and_frame_effects() {
+        use crate::card::{Frame, FrameEffect};
+
+        for frame_value in Frame::VARIANTS {
+            assert_eq!(
+                frame(frame_value.clone()).to_string(),
+                format!("frame:{}", frame_value)
+            );
+        }
+        for effect in FrameEffect::VARIANTS {
+            assert_eq!(
+                frame_effect(effect.clone()).to_string(),
+                format!("frame:{}", effect)
+            );
+        }
+        assert_eq!(
+            frame_effect(FrameEffect::WaxingAndWaningMoonDfc).to_string(),
+            "frame:waxingandwaningmoondfc"
+        );
+    }
+
+    #[test]
+    fn card_type_values() {
+        use crate::card::CardType;
+
+        assert_eq!(type_line(CardType::Creature).to_string(), "type:creature");
+        assert_eq!(
+            type_line(CardType::Creature)
+                .and(type_line("elf"))
+                .to_string(),
+            "(type:creature AND type:\"elf\")"
+        );
+        assert_eq!(not(type_line(CardType::Land)).to_string(), "-type:land");
+    }
+
+    #[test]
+    fn release_years() {
+        assert_eq!(year(1995).to_string(), "year:1995");
+        assert_eq!(year(gte(2020)).to_string(), "year>=2020");
+        assert_eq!(
+            year(lt(2000)).and(type_line("elf")).to_string(),
+            "(year<2000 AND type:\"elf\")"
+        );
+    }
+
+    #[test]
+    fn partial_dates() {
+        let matrix = vec![
+            (Date::new(1995, None, None), Some("1995")),
+            (Date::new(2003, Some(7), None), Some("2003-07")),
+            (Date::new(2021, Some(12), Some(31)), Some("2021-12-31")),
+            (Date::new(2021, Some(0), None), None),
+            (Date::new(2021, Some(1), Some(32)), None),
+            (Date::new(2021, None, Some(1)), None),
+        ];
+
+        for (date, expected) in matrix {
+            assert_eq!(date.ok().map(|d| d.to_string()).as_deref(), expected);
+        }
+        let since = date(gte(Date::new(1995, Some(6), None).unwrap()));
+        assert_eq!(since.to_string(), "date>=1995-06");
+    }
+
+    #[test]
+    fn dates_and_set_codes() {
+        use crate::set::SetCode;
+
+        let day = chrono::NaiveDate::from_ymd_opt(2020, 7, 3).unwrap();
+        assert_eq!(date(day).to_string(), "date:2020-07-03");
+        assert_eq!(date(lt(day)).to_string(), "date<2020-07-03");
+        let m21 = SetCode::new("m21").unwrap();
+        assert_eq!(date(m21).to_string(), "date:m21");
+        assert_eq!(date(gte(m21)).to_string(), "date>=m21");
+    }
+
+    #[test]
+    fn commander_identity() {
+        use crate::card::Colors;
+
+        assert_eq!(color_identity(Colors::ESPER).to_string(), "identity:WUB");
+        assert_eq!(
+            color_identity(lte(Colors::ESPER)).to_string(),
+            "identity<=WUB"
+        );
+    }
+
+    #[test]
+    fn language_values() {
+        use crate::card::Language;
+
+        assert_eq!(language(Language::Japanese).to_string(), "language:ja");
+        assert_eq!(in_language(Language::AncientGreek).to_string(), "in:grc");
+    }
+
+    #[test]
+    fn mana_costs_are_checked() {
+        use crate::card::ManaCost;
+
+        assert_eq!(mana("{2}{W/U}").unwrap().to_string(), "mana:{2}{W/U}");
+        assert_eq!(
+            mana("{r}{r}".to_string()).unwrap().to_string(),
+            "mana:{R}{R}"
         );
+        let cost = ManaCost::parse("{G}{G}").unwrap();
+        assert_eq!(mana(cost.clone()).unwrap().to_string(), "mana:{G}{G}");
+        assert_eq!(mana(lt(cost)).unwrap().to_string(), "mana<{G}{G}");
+
+        assert!(mana("{Q}{Q}").is_err());
+        assert!(mana("W").is_err());
+        assert!(mana(gte("{G}{Q}")).is_err());
+    }
+
+    #[test]
+    fn printed_in_values() {
+        use crate::card::{Language, Rarity};
+        use crate::set::{SetCode, SetType};
+
+        let war = SetCode::new("war").unwrap();
+        assert_eq!(set(war).to_string(), "set:war");
+        assert_eq!(in_set(war).to_string(), "in:war");
+        assert_eq!(rarity(Rarity::Rare).to_string(), "rarity:rare");
+        assert_eq!(in_rarity(Rarity::Rare).to_string(), "in:rare");
+        assert_eq!(
+            in_set_type(SetType::Masterpiece).to_string(),
+            "in:masterpiece"
+        );
+        assert_eq!(in_language(Language::Japanese).to_string(), "in:ja");
+        assert_eq!(
+            in_set(war).and(not(in_rarity(Rarity::Common))).to_string(),
+            "(in:war AND -in:common)"
+        );
+    }
+
+    #[test]
+    fn game_values() {
+        use crate::card::Game;
+
+        assert_eq!(game(Game::Arena).to_string(), "game:arena");
+        assert_eq!(in_game(Game::Mtgo).to_string(), "in:mtgo");
+    }
+
+    #[test]
+    fn artwork_values() {
+        let id = crate::Uuid::nil();
+        assert_eq!(
+            illustration(id).to_string(),
+            "illustration:00000000-0000-0000-0000-000000000000"
+        );
+        assert_eq!(
+            artist("Rebecca Guay").to_string(),
+            "artist:\"Rebecca Guay\""
+        );
+        assert_eq!(artist_count(gt(1)).to_string(), "artists>1");
+    }
+
+    #[test]
+    fn printings_sharing_illustration() {
+        let card = Card::named("Counterspell").unwrap();
+        let id = card.illustration_id.unwrap();
+        let printings = SearchOptions::with_query(illustration(id))
+            .unique(UniqueStrategy::Prints)
+            .search_all()
+            .unwrap();
+        assert!(printings.iter().any(|c| c.id == card.id));
+        assert!(printings.iter().all(|c| c.illustration_id == Some(id)));
+    }
+
+    #[test]
+    fn guild_watermark() {
+        use crate::card::Watermark;
+
+        let card = watermark(Watermark::Selesnya).random().unwrap();
+        assert_eq!(card.watermark_parsed(), Some(Watermark::Selesnya));
+    }
+
+    #[test]
+    fn loyalty_zero_matches_x() {
+        // Nissa, Steward of Elements has a starting loyalty of X.
+        let cards = loyalty(0)
+            .and(type_line("planeswalker"))
+            .search_all()
+            .unwrap();
+        assert!(cards.iter().any(|c| c.name == "Nissa, Steward of Elements"));
     }
 
     #[test]
@@ -291,6 +704,95 @@ mod tests {
         assert!(card.into_iter().any(|c| &c.name == "Infinity Elemental"));
     }
 
+    #[test]
+    fn regex_escapes_slashes() {
+        let matrix = [
+            (r"^fog$", r"/^fog$/"),
+            (r"foo/bar", r"/foo\/bar/"),
+            (r"foo\/bar", r"/foo\/bar/"),
+            (r"foo\\/bar", r"/foo\\\/bar/"),
+            (r"\d+/\d+", r"/\d+\/\d+/"),
+        ];
+        for (pattern, expected) in matrix.iter() {
+            assert_eq!(Regex::from(pattern).to_string(), *expected);
+        }
+        assert_eq!(
+            name(Regex::from("a/b")).query_string().unwrap(),
+            "q=name%3A%2Fa%5C%2Fb%2F"
+        );
+    }
+
+    #[test]
+    fn flavor_text_forms() {
+        assert_eq!(flavor_text("Yawgmoth").to_string(), "flavor:\"Yawgmoth\"");
+        assert_eq!(flavor_text(Regex::from("^\"")).to_string(), "flavor:/^\"/");
+    }
+
+    #[test]
+    fn godzilla_flavor_name() {
+        let printings = SearchOptions::with_query(exact("Zilortha, Strength Incarnate"))
+            .unique(UniqueStrategy::Prints)
+            .search_all()
+            .unwrap();
+        assert!(printings
+            .iter()
+            .any(|card| card.display_name() == "Godzilla, King of the Monsters"));
+    }
+
+    #[test]
+    fn oracle_text_and_regex_forms() {
+        let matrix = vec![
+            (oracle_text("draw a card"), "oracle:\"draw a card\""),
+            (
+                oracle_text(Regex::from("draw a card")),
+                "oracle:/draw a card/",
+            ),
+            (oracle_text(Regex::from("^{T}:")), "oracle:/^{T}:/"),
+            (full_oracle_text("flying"), "fulloracle:\"flying\""),
+            (
+                full_oracle_text(Regex::from(r"\(.*\)")),
+                r"fulloracle:/\(.*\)/",
+            ),
+        ];
+
+        for (query, expected) in matrix {
+            assert_eq!(query.to_string(), expected);
+        }
+        assert_eq!(
+            oracle_text(Regex::from("a/b")).query_string().unwrap(),
+            "q=oracle%3A%2Fa%5C%2Fb%2F"
+        );
+    }
+
+    #[test]
+    fn quotes_are_removed_from_text_values() {
+        assert_eq!(
+            name("\"Ach! Hans, Run!\"").to_string(),
+            "name:\"Ach! Hans, Run!\""
+        );
+        assert_eq!(
+            exact("\"Ach! Hans, Run!\"").to_string(),
+            "!\"Ach! Hans, Run!\""
+        );
+        assert_eq!(
+            oracle_text(String::from("named \"Hans\"")).to_string(),
+            "oracle:\"named Hans\""
+        );
+    }
+
+    #[test]
+    fn random_query_only_has_q() {
+        let mut url = Url::parse("http://localhost").unwrap();
+        SearchOptions::new()
+            .query(keyword("storm"))
+            .unique(UniqueStrategy::Art)
+            .sort(SortOrder::Usd, SortDirection::Ascending)
+            .extras(true)
+            .write_random_query(&mut url)
+            .unwrap();
+        assert_eq!(url.query(), Some("q=keyword%3A%22storm%22"));
+    }
+
     #[test]
     fn query_string_sanity_check() {
         let query = cmc(4).and(name("Yargle"));
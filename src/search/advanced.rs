@@ -30,6 +30,8 @@ pub struct SearchOptions {
     include_variations: bool,
     #[serde(rename = "q", serialize_with = "serialize_query")]
     query: Query,
+    #[serde(skip)]
+    page_limit: Option<usize>,
 }
 
 fn is_default<T: Default + PartialEq>(value: &T) -> bool {
@@ -47,6 +49,14 @@ impl Search for SearchOptions {
         ))?;
         Ok(())
     }
+
+    fn write_random_query(&self, url: &mut Url) -> crate::Result<()> {
+        super::write_query_string(&self.query, url)
+    }
+
+    fn max_pages(&self) -> Option<usize> {
+        self.page_limit
+    }
 }
 
 impl SearchOptions {
@@ -103,13 +113,35 @@ impl SearchOptions {
         self
     }
 
-    /// If true, extra cards (tokens, planes, etc) will be included.
+    /// If true, extra cards (tokens, planes, etc) will be included. Sends
+    /// `include_extras=true`.
+    ///
+    /// This and [`multilingual`][SearchOptions::multilingual] and
+    /// [`variations`][SearchOptions::variations] are all false by default.
+    /// They add cards to the results before duplicates are removed, so with
+    /// the default [`UniqueStrategy::Cards`] each card is still only returned
+    /// once. Use [`UniqueStrategy::Prints`] to get every printing they add.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use scryfall::search::prelude::*;
+    /// let languages = SearchOptions::with_query(exact("Lightning Bolt"))
+    ///     .multilingual(true)
+    ///     .unique(UniqueStrategy::Prints)
+    ///     .search()
+    ///     .unwrap()
+    ///     .map(|card| card.unwrap().lang)
+    ///     .collect::<std::collections::HashSet<_>>();
+    /// assert!(languages.len() > 1);
+    /// ```
     pub fn extras(&mut self, include_extras: bool) -> &mut Self {
         self.include_extras = include_extras;
         self
     }
 
     /// If true, cards in every language supported by Scryfall will be included.
+    /// Sends `include_multilingual=true`. See
+    /// [`extras`][SearchOptions::extras].
     pub fn multilingual(&mut self, include_multilingual: bool) -> &mut Self {
         self.include_multilingual = include_multilingual;
         self
@@ -117,20 +149,46 @@ impl SearchOptions {
 
     /// If true, rare care variants will be included, like the
     /// [Hairy Runesword](https://scryfall.com/card/drk/107%E2%80%A0/runesword).
+    /// Sends `include_variations=true`. See [`extras`][SearchOptions::extras].
     pub fn variations(&mut self, include_variations: bool) -> &mut Self {
         self.include_variations = include_variations;
         self
     }
+
+    /// Caps how many pages [`search`][Search::search] and
+    /// [`search_all`][Search::search_all] fetch for this search, counting the
+    /// first page. This bounds the number of requests made to Scryfall, rather
+    /// than the number of cards returned.
+    ///
+    /// When the iterator stops because of the limit, the last page it fetched
+    /// may still report [`has_more`][crate::list::List::has_more]. The
+    /// remaining cards can be requested with
+    /// [`ListIter::next_page`][crate::list::ListIter::next_page].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use scryfall::search::prelude::*;
+    /// let cards = SearchOptions::with_query(type_line("creature"))
+    ///     .page_limit(1)
+    ///     .search_all()
+    ///     .unwrap();
+    /// assert_eq!(cards.len(), 175);
+    /// ```
+    pub fn page_limit(&mut self, pages: usize) -> &mut Self {
+        self.page_limit = Some(pages);
+        self
+    }
 }
 
 /// The unique parameter specifies if Scryfall should remove “duplicate” results
 /// in your query.
-#[derive(Serialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Serialize, Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum UniqueStrategy {
     /// Removes duplicate gameplay objects (cards that share a name and have the
     /// same functionality). For example, if your search matches more than
     /// one print of Pacifism, only one copy of Pacifism will be returned.
+    #[default]
     Cards,
     /// Returns only one copy of each unique artwork for matching cards. For
     /// example, if your search matches more than one print of Pacifism, one
@@ -144,17 +202,16 @@ pub enum UniqueStrategy {
     Prints,
 }
 
-impl Default for UniqueStrategy {
-    fn default() -> Self {
-        UniqueStrategy::Cards
-    }
-}
-
 /// The order parameter determines how Scryfall should sort the returned cards.
-#[derive(Serialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+///
+/// The arrows in each variant's docs show the direction used with
+/// [`SortDirection::Auto`]. Most orders are ascending by default, but
+/// `Released`, `Review` and `Spoiled` list the newest cards first.
+#[derive(Serialize, Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum SortOrder {
     /// Sort cards by name, A → Z
+    #[default]
     Name,
     /// Sort cards by their set and collector number: AAA/#1 → ZZZ/#999
     Set,
@@ -180,36 +237,142 @@ pub enum SortOrder {
     Toughness,
     /// Sort cards by their EDHREC ranking: lowest → highest
     Edhrec,
+    /// Sort cards by their Penny Dreadful ranking: lowest → highest
+    Penny,
     /// Sort cards by their front-side artist name: A → Z
     Artist,
-}
-
-impl Default for SortOrder {
-    fn default() -> Self {
-        SortOrder::Name
-    }
+    /// Sort cards by how relevant they are for set review: Newest → Oldest.
+    /// Combined with [`PrintingIs::NewCard`][crate::search::param::criteria::PrintingIs::NewCard]
+    /// and a set code, this lists the cards previewed for an upcoming set.
+    Review,
+    /// Sort cards by the date they were first previewed: Newest → Oldest
+    Spoiled,
 }
 
 /// Which direction the sorting should occur:
-#[derive(Serialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+///
+/// `Auto` is the default, and leaves the `dir` parameter out of the request.
+#[derive(Serialize, Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum SortDirection {
-    /// Scryfall will automatically choose the most intuitive direction to sort
+    /// Scryfall will automatically choose the most intuitive direction to sort,
+    /// which is the one described in each [`SortOrder`] variant
+    #[default]
     Auto,
-    /// Sort ascending (flip the direction of the arrows in [`SortMethod`])
-    ///
-    /// [`SortMethod`]: enum.SortMethod.html
+    /// Sort ascending (flip the direction of the arrows in [`SortOrder`])
     #[serde(rename = "asc")]
     Ascending,
-    /// Sort descending (flip the direction of the arrows in [`SortMethod`])
-    ///
-    /// [`SortMethod`]: enum.SortMethod.html
+    /// Sort descending (flip the direction of the arrows in [`SortOrder`])
     #[serde(rename = "desc")]
     Descending,
 }
 
-impl Default for SortDirection {
-    fn default() -> Self {
-        SortDirection::Auto
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sort_order_tokens() {
+        let matrix = vec![
+            (SortOrder::Set, "set"),
+            (SortOrder::Released, "released"),
+            (SortOrder::Rarity, "rarity"),
+            (SortOrder::Color, "color"),
+            (SortOrder::Usd, "usd"),
+            (SortOrder::Tix, "tix"),
+            (SortOrder::Eur, "eur"),
+            (SortOrder::Cmc, "cmc"),
+            (SortOrder::Power, "power"),
+            (SortOrder::Toughness, "toughness"),
+            (SortOrder::Edhrec, "edhrec"),
+            (SortOrder::Penny, "penny"),
+            (SortOrder::Artist, "artist"),
+            (SortOrder::Review, "review"),
+            (SortOrder::Spoiled, "spoiled"),
+        ];
+
+        for (order, token) in matrix {
+            let query = SearchOptions::new().order(order).query_string().unwrap();
+            assert!(
+                query.starts_with(&format!("order={}&", token)),
+                "{:?}: {}",
+                order,
+                query
+            );
+        }
+        // `name` is the default, so it is left out of the query.
+        let query = SearchOptions::new()
+            .order(SortOrder::Name)
+            .query_string()
+            .unwrap();
+        assert!(!query.contains("order="), "{}", query);
+    }
+
+    #[test]
+    fn include_flags() {
+        let default = SearchOptions::new().query_string().unwrap();
+        assert!(!default.contains("include_"), "{}", default);
+
+        let extras = SearchOptions::new().extras(true).query_string().unwrap();
+        let multilingual = SearchOptions::new()
+            .multilingual(true)
+            .query_string()
+            .unwrap();
+        let variations = SearchOptions::new()
+            .variations(true)
+            .query_string()
+            .unwrap();
+        for (query, param) in [
+            (extras, "include_extras=true"),
+            (multilingual, "include_multilingual=true"),
+            (variations, "include_variations=true"),
+        ] {
+            assert!(query.contains(param), "{}", query);
+            assert_eq!(query.matches("include_").count(), 1, "{}", query);
+        }
+
+        let query = SearchOptions::new()
+            .extras(true)
+            .multilingual(true)
+            .variations(true)
+            .unique(UniqueStrategy::Prints)
+            .query_string()
+            .unwrap();
+        assert!(
+            query.contains(
+                "unique=prints&page=1&include_extras=true&include_multilingual=true&include_variations=true"
+            ),
+            "{}",
+            query
+        );
+    }
+
+    #[test]
+    fn review_order_for_new_cards() {
+        use crate::search::prelude::*;
+        use crate::set::SetCode;
+
+        let dsk = SetCode::new("dsk").unwrap();
+        let query = SearchOptions::with_query(PrintingIs::NewCard.and(set(dsk)))
+            .order(SortOrder::Review)
+            .query_string()
+            .unwrap();
+        assert_eq!(
+            query,
+            "order=review&page=1&q=%28new%3Acard+AND+set%3Adsk%29"
+        );
+    }
+
+    #[test]
+    fn sort_direction_tokens() {
+        let query = |dir| {
+            SearchOptions::new()
+                .sort(SortOrder::Rarity, dir)
+                .query_string()
+                .unwrap()
+        };
+        assert!(!query(SortDirection::Auto).contains("dir="));
+        assert!(query(SortDirection::Ascending).contains("dir=asc&"));
+        assert!(query(SortDirection::Descending).contains("dir=desc&"));
     }
 }
@@ -26,6 +26,7 @@ use url::Url;
 use self::compare::CompareOp;
 use self::criteria::Criterion;
 use self::value::ValueKind;
+use crate::card::Card;
 use crate::search::query::Query;
 use crate::search::Search;
 
@@ -65,21 +66,39 @@ impl Search for Param {
 }
 
 impl Param {
-    fn exact(value: impl Into<String>) -> Self {
+    pub(crate) fn exact(value: impl Into<String>) -> Self {
         Param(ParamImpl::ExactName(value.into()))
     }
 
-    fn criterion(criterion: Criterion) -> Self {
+    pub(crate) fn criterion(criterion: Criterion) -> Self {
         Param(ParamImpl::Criterion(criterion))
     }
 
-    fn value(kind: ValueKind, value: impl ToString) -> Self {
+    pub(crate) fn value(kind: ValueKind, value: impl ToString) -> Self {
         Param(ParamImpl::Value(kind, value.to_string()))
     }
 
-    fn comparison(kind: ValueKind, op: CompareOp, value: impl ToString) -> Self {
+    pub(crate) fn comparison(kind: ValueKind, op: CompareOp, value: impl ToString) -> Self {
         Param(ParamImpl::Comparison(kind, op, value.to_string()))
     }
+
+    /// Checks if `card` matches this parameter. Returns `None` if this
+    /// parameter can't be checked without asking Scryfall. See
+    /// [`Query::matches`].
+    pub(crate) fn matches(&self, card: &Card) -> Option<bool> {
+        match &self.0 {
+            ParamImpl::ExactName(name) => Some(
+                card.name.eq_ignore_ascii_case(name)
+                    || card
+                        .faces()
+                        .iter()
+                        .any(|face| face.name.eq_ignore_ascii_case(name)),
+            ),
+            ParamImpl::Criterion(_) => None,
+            ParamImpl::Value(kind, value) => kind.matches(None, value, card),
+            ParamImpl::Comparison(kind, op, value) => kind.matches(Some(*op), value, card),
+        }
+    }
 }
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
@@ -94,14 +113,16 @@ impl fmt::Display for ParamImpl {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             ParamImpl::Criterion(prop) => write!(f, "{}", prop),
-            ParamImpl::ExactName(name) => write!(f, "!\"{}\"", name),
+            // Scryfall does not support escaping quotes, so they are removed.
+            ParamImpl::ExactName(name) => write!(f, "!\"{}\"", name.replace('"', "")),
             ParamImpl::Value(kind, value) => kind.fmt_value(value.as_str(), f),
-            ParamImpl::Comparison(kind, op, value) => kind.fmt_comparison(*op, &*value, f),
+            ParamImpl::Comparison(kind, op, value) => kind.fmt_comparison(*op, value, f),
         }
     }
 }
 
-/// Matches a card whose name is exactly `name`.
+/// Matches a card whose name is exactly `name`. Scryfall does not support
+/// escaping quotes, so any double quotes in `name` are removed.
 pub fn exact(name: impl Into<String>) -> Query {
     Query::Param(Param::exact(name))
 }
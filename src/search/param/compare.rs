@@ -39,6 +39,19 @@ impl<T: fmt::Display> fmt::Display for Compare<T> {
     }
 }
 
+impl<T> Compare<T> {
+    /// Converts the compared value with `f`, keeping the operator.
+    pub(crate) fn try_map<U>(
+        self,
+        f: impl FnOnce(T) -> crate::Result<U>,
+    ) -> crate::Result<Compare<U>> {
+        Ok(Compare {
+            op: self.op,
+            value: f(self.value)?,
+        })
+    }
+}
+
 impl<T: ParamValue> ParamValue for Compare<T> {
     fn into_param(self, kind: ValueKind) -> Param {
         Param::comparison(kind, self.op, self.value)
@@ -46,7 +59,7 @@ impl<T: ParamValue> ParamValue for Compare<T> {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-pub(super) enum CompareOp {
+pub(crate) enum CompareOp {
     Lte,
     Lt,
     Gte,
@@ -55,7 +68,7 @@ pub(super) enum CompareOp {
     Neq,
 }
 
-pub(super) const fn compare_op_str(op: Option<CompareOp>) -> &'static str {
+pub(crate) const fn compare_op_str(op: Option<CompareOp>) -> &'static str {
     match op {
         None => ":",
         Some(CompareOp::Lte) => "<=",
@@ -86,15 +99,46 @@ macro_rules! compare_fns {
 
 compare_fns! {
     #[doc = "Less than `x`."]
+    #[doc = ""]
+    #[doc = "```rust"]
+    #[doc = "# use scryfall::search::prelude::*;"]
+    #[doc = "assert_eq!(cmc(lt(2)).to_string(), \"cmc<2\");"]
+    #[doc = "```"]
     lt => Lt,
     #[doc = "Less than or equal to `x`."]
+    #[doc = ""]
+    #[doc = "```rust"]
+    #[doc = "# use scryfall::search::prelude::*;"]
+    #[doc = "assert_eq!(power(lte(NumProperty::Toughness)).to_string(), \"power<=toughness\");"]
+    #[doc = "```"]
     lte => Lte,
     #[doc = "Greater than or equal to `x`."]
+    #[doc = ""]
+    #[doc = "```rust"]
+    #[doc = "# use scryfall::search::prelude::*;"]
+    #[doc = "assert_eq!(loyalty(gte(5)).to_string(), \"loyalty>=5\");"]
+    #[doc = "```"]
     gte => Gte,
     #[doc = "Greater than `x`."]
+    #[doc = ""]
+    #[doc = "```rust"]
+    #[doc = "# use scryfall::search::prelude::*;"]
+    #[doc = "use scryfall::card::Rarity;"]
+    #[doc = "assert_eq!(rarity(gt(Rarity::Rare)).to_string(), \"rarity>rare\");"]
+    #[doc = "```"]
     gt => Gt,
     #[doc = "Equal to `x`."]
+    #[doc = ""]
+    #[doc = "```rust"]
+    #[doc = "# use scryfall::search::prelude::*;"]
+    #[doc = "assert_eq!(cmc(eq(2)).to_string(), \"cmc=2\");"]
+    #[doc = "```"]
     eq => Eq,
     #[doc = "Not equal to `x`."]
+    #[doc = ""]
+    #[doc = "```rust"]
+    #[doc = "# use scryfall::search::prelude::*;"]
+    #[doc = "assert_eq!(toughness(neq(1)).to_string(), \"toughness!=1\");"]
+    #[doc = "```"]
     neq => Neq,
 }
@@ -6,6 +6,7 @@ use std::fmt;
 
 use crate::search::param::Param;
 use crate::search::query::Query;
+use crate::util::from_str_by_display;
 
 /// A search criterion for filtering cards. Each card is tagged with various
 /// searchable properties, representing boolean parameters. Some of the criteria
@@ -115,6 +116,11 @@ pub enum CardIs {
     ModalDfc,
     /// Find cards with meld.
     Meld,
+    /// Find cards with a back face, such as transforming and modal dual-face
+    /// cards.
+    DoubleFaced,
+    /// Find adventurer cards.
+    Adventure,
     /// Find leveler cards.
     Leveler,
     /// Find cards that are cast as spells.
@@ -224,6 +230,8 @@ impl fmt::Display for CardIs {
                 CardIs::Transform => "transform",
                 CardIs::ModalDfc => "modal_dfc",
                 CardIs::Meld => "meld",
+                CardIs::DoubleFaced => "dfc",
+                CardIs::Adventure => "adventure",
                 CardIs::Leveler => "leveler",
                 CardIs::Spell => "spell",
                 CardIs::Permanent => "permanent",
@@ -261,6 +269,16 @@ impl fmt::Display for CardIs {
     }
 }
 
+from_str_by_display! {
+    CardIs, "card criterion", [
+        ColorIndicator, EvenCmc, OddCmc, Phyrexian, Hybrid, Split, Flip, Transform, ModalDfc,
+        Meld, DoubleFaced, Adventure, Leveler, Spell, Permanent, Historic, Party, Modal, Vanilla, FrenchVanilla, Funny,
+        Commander, Brawler, Companion, Reserved, BicycleLand, TricycleLand, BounceLand,
+        CanopyLand, CheckLand, DualLand, FastLand, FetchLand, FilterLand, GainLand, PainLand,
+        ScryLand, ShadowLand, ShockLand, StorageLand, CreatureLand, TriLand, BattleLand,
+    ]
+}
+
 impl From<CardIs> for Query {
     fn from(card: CardIs) -> Self {
         Criterion::Card(card).into()
@@ -338,6 +356,17 @@ pub enum PrintingIs {
     Spotlight,
     /// Find printings that are in the Masterpiece Series.
     Masterpiece,
+    /// Find printings that are available in etched foil.
+    Etched,
+    /// Find oversized printings, such as Commander display cards.
+    Oversized,
+    /// Find printings that are found in booster packs.
+    Booster,
+    /// Find printings without text in the text box.
+    Textless,
+    /// Find printings with colorshifted frames, such as the cards from Planar
+    /// Chaos.
+    Colorshifted,
     /// Find printings that have only been in a single set.
     Unique,
     /// Find first printings (digital or paper).
@@ -383,12 +412,25 @@ impl fmt::Display for PrintingIs {
                 PrintingIs::FirstPrint => "first_print",
                 PrintingIs::Reprint => "reprint",
                 PrintingIs::Masterpiece => "masterpiece",
+                PrintingIs::Etched => "etched",
+                PrintingIs::Oversized => "oversized",
+                PrintingIs::Booster => "booster",
+                PrintingIs::Textless => "textless",
+                PrintingIs::Colorshifted => "colorshifted",
                 PrintingIs::Unique => "unique",
             }
         )
     }
 }
 
+from_str_by_display! {
+    PrintingIs, "printing criterion", [
+        NewCard, NewRarity, NewArt, NewArtist, NewFlavor, NewFrame, NewLanguage, Watermark,
+        Full, Nonfoil, Foil, HiRes, Digital, Promo, Spotlight, Masterpiece, Etched, Oversized,
+        Booster, Textless, Colorshifted, Unique, FirstPrint, Reprint,
+    ]
+}
+
 impl From<PrintingIs> for Query {
     fn from(printing: PrintingIs) -> Self {
         Criterion::Printing(printing).into()
@@ -400,8 +442,26 @@ mod tests {
     use strum::IntoEnumIterator;
 
     use super::*;
+    use crate::search::query::not;
     use crate::search::Search;
 
+    #[test]
+    fn criteria_build_queries() {
+        let criteria = CardIs::iter()
+            .map(Criterion::Card)
+            .chain(PrintingIs::iter().map(Criterion::Printing));
+        for criterion in criteria {
+            let text = criterion.to_string();
+            let (prefix, value) = text.split_once(':').unwrap();
+            assert!(["is", "has", "new", "cmc"].contains(&prefix), "{}", text);
+            assert!(!value.is_empty() && !value.contains(' '), "{}", text);
+
+            assert_eq!(Query::from(criterion).to_string(), text);
+            assert_eq!(not(criterion).to_string(), format!("-{}", text));
+            assert_eq!(text.parse::<Query>().unwrap(), criterion.into());
+        }
+    }
+
     #[test]
     #[ignore]
     fn all_card_is() {
@@ -13,6 +13,9 @@ use std::fmt;
 pub use self::functions::*;
 use crate::search::param::compare::{compare_op_str, Compare, CompareOp};
 use crate::search::param::Param;
+use crate::util::from_str_by_display;
+
+mod matches;
 
 /// The type of parameter that this is. Corresponds to the name before the ':'
 /// or other operator.
@@ -35,6 +38,64 @@ impl ValueKind {
     ) -> fmt::Result {
         write!(f, "{}{}{}", self, compare_op_str(Some(op)), value)
     }
+
+    /// Looks up the parameter kind for a keyword, such as `t` or `oracle`.
+    /// The value is needed to tell apart the different `in:` parameters.
+    pub(crate) fn from_keyword(keyword: &str, value: &str) -> Option<Self> {
+        use crate::card::{Game, Rarity};
+        use crate::set::SetType;
+
+        let kind = match keyword.to_ascii_lowercase().as_str() {
+            "c" | "color" => ValueKindImpl::Color,
+            "id" | "identity" | "ci" => ValueKindImpl::ColorIdentity,
+            "t" | "type" => ValueKindImpl::Type,
+            "o" | "oracle" => ValueKindImpl::Oracle,
+            "fo" | "fulloracle" => ValueKindImpl::FullOracle,
+            "kw" | "keyword" => ValueKindImpl::Keyword,
+            "m" | "mana" => ValueKindImpl::Mana,
+            "devotion" => ValueKindImpl::Devotion,
+            "produces" => ValueKindImpl::Produces,
+            "r" | "rarity" => ValueKindImpl::Rarity,
+            "s" | "e" | "set" | "edition" => ValueKindImpl::Set,
+            "cn" | "number" => ValueKindImpl::Number,
+            "b" | "block" => ValueKindImpl::Block,
+            "st" | "settype" => ValueKindImpl::SetType,
+            "cube" => ValueKindImpl::Cube,
+            "f" | "format" | "legal" => ValueKindImpl::Format,
+            "banned" => ValueKindImpl::Banned,
+            "restricted" => ValueKindImpl::Restricted,
+            "cheapest" => ValueKindImpl::Cheapest,
+            "a" | "artist" => ValueKindImpl::Artist,
+            "il" | "illustration" => ValueKindImpl::Illustration,
+            "ft" | "flavor" => ValueKindImpl::Flavor,
+            "wm" | "watermark" => ValueKindImpl::Watermark,
+            "border" => ValueKindImpl::BorderColor,
+            "frame" => ValueKindImpl::Frame,
+            "date" => ValueKindImpl::Date,
+            "game" => ValueKindImpl::Game,
+            "lang" | "language" => ValueKindImpl::Language,
+            "name" => ValueKindImpl::Name,
+            "in" => {
+                let value = value.trim_matches('"');
+                if value.parse::<Rarity>().is_ok() {
+                    ValueKindImpl::InRarity
+                } else if value.parse::<Game>().is_ok() {
+                    ValueKindImpl::InGame
+                } else if value.parse::<SetType>().is_ok() {
+                    ValueKindImpl::InSetType
+                } else {
+                    ValueKindImpl::InSet
+                }
+            },
+            "pow" => ValueKindImpl::NumericComparable(NumProperty::Power),
+            "tou" => ValueKindImpl::NumericComparable(NumProperty::Toughness),
+            "pt" => ValueKindImpl::NumericComparable(NumProperty::PowTou),
+            "loy" => ValueKindImpl::NumericComparable(NumProperty::Loyalty),
+            "mv" | "manavalue" => ValueKindImpl::NumericComparable(NumProperty::Cmc),
+            other => ValueKindImpl::NumericComparable(other.parse().ok()?),
+        };
+        Some(ValueKind(kind))
+    }
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
@@ -62,6 +123,7 @@ enum ValueKindImpl {
     Restricted,
     Cheapest,
     Artist,
+    Illustration,
     Flavor,
     Watermark,
     BorderColor,
@@ -149,6 +211,13 @@ impl fmt::Display for NumProperty {
     }
 }
 
+from_str_by_display! {
+    NumProperty, "numeric property", [
+        Power, Toughness, PowTou, Loyalty, Cmc, ArtistCount, Usd, UsdFoil, Eur, Tix,
+        IllustrationCount, PrintCount, SetCount, PaperPrintCount, PaperSetCount, Year,
+    ]
+}
+
 impl fmt::Display for ValueKind {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -175,6 +244,7 @@ impl fmt::Display for ValueKind {
                 ValueKindImpl::Restricted => "restricted",
                 ValueKindImpl::Cheapest => "cheapest",
                 ValueKindImpl::Artist => "artist",
+                ValueKindImpl::Illustration => "illustration",
                 ValueKindImpl::Flavor => "flavor",
                 ValueKindImpl::Watermark => "watermark",
                 ValueKindImpl::BorderColor => "border",
@@ -209,7 +279,7 @@ pub trait ParamValue: fmt::Debug + fmt::Display {
 /// A numeric value for a parameter.
 ///
 /// Searchable parameters which directly use a `NumericValue` argument include
-/// [`color_count()`] and [`collector_number()`]. Other parameters, such as
+/// [`color_count()`] and [`artist_count()`]. Other parameters, such as
 /// [`power()`] and [`toughness()`], can be directly compared against one
 /// another. See [`NumericComparableValue`] for more information.
 ///
@@ -222,6 +292,7 @@ macro_rules! impl_numeric_values {
             impl ParamValue for $Ty {}
             impl NumericValue for $Ty {}
             impl NumericComparableValue for $Ty {}
+            impl CollectorNumberValue for $Ty {}
         )*
     };
 }
@@ -265,20 +336,18 @@ impl NumericComparableValue for NumProperty {}
 /// operations.
 ///
 /// Searchable parameters that directly use a `TextValue` argument include
-/// [`watermark()`] and [`keyword()`]. Additionally, many types can
+/// [`artist()`] and [`keyword()`]. Additionally, many types can
 pub trait TextValue: ParamValue {}
 
 /// Helper struct for a quoted value. The `Display` impl for this struct
-/// surrounds the value in quotes. Representations that already contain
-/// quotes are not supported.
+/// surrounds the value in quotes. Scryfall does not support escaping quotes,
+/// so any double quotes in the value itself are removed.
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 struct Quoted<T>(T);
 
 impl<T: fmt::Display> fmt::Display for Quoted<T> {
-    // TODO(msmorgan): This breaks if the value has quotes in it.
-    //     Scryfall does not support quote escaping.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "\"{}\"", self.0)
+        write!(f, "\"{}\"", self.0.to_string().replace('"', ""))
     }
 }
 
@@ -311,7 +380,9 @@ impl TextValue for &str {}
 /// and [`flavor_text()`]. To specify a regular expression, use the
 /// [`Regex`] type from this module.
 ///
-/// This trait is implemented for all `TextValue` types and `Regex`.
+/// This trait is implemented for all `TextValue` types and `Regex`. The
+/// [`CardType`][crate::card::CardType] enum can also be passed to
+/// [`type_line()`].
 ///
 /// For more information on supported regular expressions, see the
 /// [official help page](https://scryfall.com/docs/regular-expressions).
@@ -321,9 +392,12 @@ impl<T: TextValue> TextOrRegexValue for T {}
 
 /// `Regex` is a newtype for String, indicating that the string represents a
 /// regular expression and should be surrounded by slashes in the search
-/// query.
+/// query. Forward slashes inside the pattern are escaped, unless they already
+/// are.
 ///
-/// For more information on supported regular expressions, see the
+/// Scryfall uses a JavaScript-like regular expression dialect, with a few
+/// additions such as `~` for the card's own name and `\sm` for any mana
+/// symbol. For more information on supported regular expressions, see the
 /// [official help page](https://scryfall.com/docs/regular-expressions).
 ///
 /// # Example
@@ -342,7 +416,16 @@ pub struct Regex(pub String);
 
 impl fmt::Display for Regex {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "/{}/", self.0.replace('/', "\\/"))
+        f.write_str("/")?;
+        let mut escaped = false;
+        for c in self.0.chars() {
+            if c == '/' && !escaped {
+                f.write_str("\\")?;
+            }
+            escaped = c == '\\' && !escaped;
+            write!(f, "{}", c)?;
+        }
+        f.write_str("/")
     }
 }
 
@@ -355,6 +438,9 @@ impl<T: AsRef<str>> From<T> for Regex {
 impl ParamValue for Regex {}
 impl TextOrRegexValue for Regex {}
 
+impl ParamValue for crate::card::CardType {}
+impl TextOrRegexValue for crate::card::CardType {}
+
 /// A color value represents one or more colors, or colorless/multicolored.
 /// Supports [comparison operators][super::compare].
 ///
@@ -363,8 +449,7 @@ impl TextOrRegexValue for Regex {}
 ///
 /// This type is implemented for [`Color`][crate::card::Color],
 /// [`Colors`][crate::card::Colors],
-/// [`Multicolored`][crate::card:: Multicolored],
-/// and all [`TextValue`] types.
+/// [`Multicolored`][crate::card:: Multicolored], and all [`TextValue`] types.
 pub trait ColorValue: ParamValue {}
 
 impl<T: ColorValue> ColorValue for Compare<T> {}
@@ -380,6 +465,57 @@ impl ColorValue for crate::card::Multicolored {}
 
 impl<T: TextValue> ColorValue for T {}
 
+/// A mana cost value, such as `{2}{W}{W}`. Supports
+/// [comparison operators][super::compare], which compare the symbols in the
+/// costs, so `mana(gte("{G}{G}"))` matches costs with at least two green
+/// symbols.
+///
+/// The only parameter that takes a `ManaValue` is [`mana()`].
+///
+/// This trait is implemented for [`ManaCost`][crate::card::ManaCost], and
+/// for `&str` and `String`, which are parsed as a mana cost. Invalid mana
+/// costs are rejected before the query is built.
+pub trait ManaValue {
+    /// The value that is written in the query, once checked.
+    type Checked: ParamValue;
+
+    /// Checks that this value is a valid mana cost.
+    fn check_mana(self) -> crate::Result<Self::Checked>;
+}
+
+impl<T: ManaValue> ManaValue for Compare<T> {
+    type Checked = Compare<T::Checked>;
+
+    fn check_mana(self) -> crate::Result<Self::Checked> {
+        self.try_map(T::check_mana)
+    }
+}
+
+impl ParamValue for crate::card::ManaCost {}
+impl ManaValue for crate::card::ManaCost {
+    type Checked = Self;
+
+    fn check_mana(self) -> crate::Result<Self> {
+        Ok(self)
+    }
+}
+
+impl ManaValue for &str {
+    type Checked = crate::card::ManaCost;
+
+    fn check_mana(self) -> crate::Result<Self::Checked> {
+        crate::card::ManaCost::parse(self)
+    }
+}
+
+impl ManaValue for String {
+    type Checked = crate::card::ManaCost;
+
+    fn check_mana(self) -> crate::Result<Self::Checked> {
+        crate::card::ManaCost::parse(&self)
+    }
+}
+
 /// A value representing an amount of devotion to one or two colors. Supports
 /// [comparison operations][super::compare].
 ///
@@ -402,6 +538,18 @@ pub trait DevotionValue: ParamValue {}
 /// A representation of a permanent's devotion to one or two colors. Use the
 /// constructors [`monocolor`][Devotion::monocolor()] and
 /// [`hybrid`][Devotion::hybrid()] to create values of this type.
+///
+/// The count is the number of mana symbols written in the query, so a count of
+/// 3 is written as `{W}{W}{W}`, not as a number.
+///
+/// # Examples
+/// ```rust
+/// # use scryfall::search::prelude::*;
+/// use scryfall::card::Color;
+/// let three_white = Devotion::monocolor(Color::White, 3);
+/// assert_eq!(devotion(three_white).to_string(), "devotion:{W}{W}{W}");
+/// assert_eq!(devotion(lt(three_white)).to_string(), "devotion<{W}{W}{W}");
+/// ```
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub struct Devotion(crate::card::Color, Option<crate::card::Color>, usize);
 
@@ -433,13 +581,14 @@ impl DevotionValue for Devotion {}
 impl DevotionValue for Compare<Devotion> {}
 
 impl Devotion {
-    /// Constructs a `Devotion` object with the given color and devotion count.
+    /// Constructs a `Devotion` object with the given color and devotion count,
+    /// the number of `color` symbols to search for.
     pub fn monocolor(color: crate::card::Color, count: usize) -> Self {
         Devotion(color, None, count)
     }
 
     /// Constructs a `Devotion` object representing devotion to two colors with
-    /// the given count.
+    /// the given count, the number of hybrid `{A/B}` symbols to search for.
     pub fn hybrid(color_a: crate::card::Color, color_b: crate::card::Color, count: usize) -> Self {
         Devotion(color_a, Some(color_b), count)
     }
@@ -508,6 +657,61 @@ impl<T: TextValue> SetValue for T {}
 impl ParamValue for crate::set::SetCode {}
 impl SetValue for crate::set::SetCode {}
 
+/// A value representing the collector number of a printing. Numbers support
+/// [comparison operators][super::compare].
+///
+/// `CollectorNumberValue` is the argument type for [`collector_number()`].
+///
+/// This trait is implemented for all numeric primitive types, the
+/// [`CollectorNumber`] type from this module, and all [`TextValue`] types.
+/// Collector numbers can have letters or symbols in them, such as `123a` or
+/// `★`, so string values are quoted.
+///
+/// Only numbers can be [compared][super::compare], as in
+/// `collector_number(gte(200))`. Scryfall compares collector numbers
+/// numerically, so whether printings with letters or symbols in their number,
+/// such as `200a` or `★`, match a comparison is up to Scryfall.
+///
+/// # Example
+/// ```rust
+/// # use scryfall::search::prelude::*;
+/// assert_eq!(collector_number(lte(100)).to_string(), "number<=100");
+/// assert_eq!(collector_number("123a").to_string(), "number:\"123a\"");
+/// assert_eq!(
+///     collector_number(CollectorNumber::from("123")).to_string(),
+///     "number:123"
+/// );
+/// ```
+pub trait CollectorNumberValue: ParamValue {}
+
+impl<T: TextValue> CollectorNumberValue for T {}
+
+impl<T: NumericValue> CollectorNumberValue for Compare<T> {}
+
+/// A collector number, such as `123`, `45a` or `★`. Numbers are written to
+/// the query as they are, and anything else is quoted.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct CollectorNumber(pub String);
+
+impl fmt::Display for CollectorNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.0.is_empty() && self.0.chars().all(|c| c.is_ascii_digit()) {
+            write!(f, "{}", self.0)
+        } else {
+            write!(f, "{}", Quoted(&self.0))
+        }
+    }
+}
+
+impl<T: AsRef<str>> From<T> for CollectorNumber {
+    fn from(number: T) -> Self {
+        CollectorNumber(number.as_ref().to_string())
+    }
+}
+
+impl ParamValue for CollectorNumber {}
+impl CollectorNumberValue for CollectorNumber {}
+
 /// A value representing a draft cube from MTGO, such as the
 /// [Vintage Cube](https://scryfall.com/cubes/vintage).
 ///
@@ -552,11 +756,51 @@ impl FormatValue for crate::format::Format {}
 ///
 /// `CurrencyValue` is used as an argument for the [`cheapest`] parameter.
 ///
-/// This trait is implemented for `String` and `&str`.
+/// This trait is implemented for `String` and `&str`, as well as the
+/// [`Currency`] enum.
 pub trait CurrencyValue: ParamValue {}
 
 impl<T: TextValue> CurrencyValue for T {}
 
+/// A currency that Scryfall has prices in.
+///
+/// # Example
+/// ```rust
+/// # use scryfall::search::prelude::*;
+/// assert_eq!(cheapest(Currency::Eur).to_string(), "cheapest:eur");
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Currency {
+    /// US Dollars.
+    Usd,
+    /// US Dollars, for foil printings.
+    UsdFoil,
+    /// Euros.
+    Eur,
+    /// Magic Online event tickets.
+    Tix,
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Currency::Usd => "usd",
+            Currency::UsdFoil => "usdfoil",
+            Currency::Eur => "eur",
+            Currency::Tix => "tix",
+        })
+    }
+}
+
+from_str_by_display! {
+    Currency, "currency", [
+        Usd, UsdFoil, Eur, Tix,
+    ]
+}
+
+impl ParamValue for Currency {}
+impl CurrencyValue for Currency {}
+
 /// A value representing a type of Magic set, such as a core set or a duel deck.
 ///
 /// `SetTypeValue` is used as the argument type for [`set_type()`] and
@@ -598,16 +842,60 @@ impl<T: TextValue> FrameValue for T {}
 impl ParamValue for crate::card::FrameEffect {}
 impl FrameValue for crate::card::FrameEffect {}
 
+/// A value representing a frame effect, such as a legendary crown or an
+/// extended art frame.
+///
+/// `FrameEffectValue` is the argument type for [`frame_effect()`].
+///
+/// This trait is implemented for the [`FrameEffect`][crate::card::FrameEffect]
+/// enum and all [`TextValue`] types.
+pub trait FrameEffectValue: FrameValue {}
+
+impl<T: TextValue> FrameEffectValue for T {}
+
+impl FrameEffectValue for crate::card::FrameEffect {}
+
 impl ParamValue for crate::card::Frame {}
 impl FrameValue for crate::card::Frame {}
 
+/// A value representing a watermark, such as a guild symbol.
+///
+/// `WatermarkValue` is the argument type for [`watermark()`].
+///
+/// This trait is implemented for the [`Watermark`][crate::card::Watermark]
+/// enum and all [`TextValue`] types.
+///
+/// # Example
+/// ```rust
+/// # use scryfall::search::prelude::*;
+/// # fn main() -> scryfall::Result<()> {
+/// use scryfall::card::Watermark;
+/// let card = watermark(Watermark::Golgari).random()?;
+/// assert_eq!(card.watermark_parsed(), Some(Watermark::Golgari));
+/// # Ok(())
+/// # }
+/// ```
+pub trait WatermarkValue: ParamValue {}
+
+impl<T: TextValue> WatermarkValue for T {}
+
+impl ParamValue for crate::card::Watermark {
+    fn into_param(self, kind: ValueKind) -> Param {
+        match self {
+            crate::card::Watermark::Other(s) => s.into_param(kind),
+            watermark => Param::value(kind, watermark),
+        }
+    }
+}
+impl WatermarkValue for crate::card::Watermark {}
+
 /// A parameter that represents a date. A set code can also be used used to
 /// stand for the date that set was released. Supports
 /// [comparison operators][super::compare].
 ///
 /// `DateValue` is the argument type for [`date()`].
 ///
-/// This trait is implemented for [`chrono::NaiveDate`],
+/// This trait is implemented for [`chrono::NaiveDate`], [`Date`],
 /// [`SetCode`][crate::set::SetCode], and any [`TextValue`] such as `String` or
 /// `&str`. When searching with a string, it must either be a valid set code or
 /// a date in the format `yyyy[-mm[-dd]]`.
@@ -627,6 +915,61 @@ impl ParamValue for chrono::NaiveDate {
 }
 impl DateValue for chrono::NaiveDate {}
 
+/// A full or partial date, for searching with [`date()`] without using
+/// [`chrono`]. Dates are written as `yyyy`, `yyyy-mm`, or `yyyy-mm-dd`.
+///
+/// # Example
+/// ```rust
+/// # use scryfall::search::prelude::*;
+/// assert_eq!(date(gte(Date::new(1995, None, None)?)).to_string(), "date>=1995");
+/// assert_eq!(date(Date::new(2020, Some(4), Some(24))?).to_string(), "date:2020-04-24");
+/// assert!(Date::new(2020, Some(13), None).is_err());
+/// # Ok::<(), scryfall::Error>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Date {
+    year: u16,
+    month: Option<u8>,
+    day: Option<u8>,
+}
+
+impl Date {
+    /// Constructs a `Date`, checking that the month is between 1 and 12 and
+    /// the day is between 1 and 31. A day can only be given together with a
+    /// month.
+    pub fn new(year: u16, month: Option<u8>, day: Option<u8>) -> crate::Result<Self> {
+        let valid = match (month, day) {
+            (None, None) => true,
+            (None, Some(_)) => false,
+            (Some(month), None) => (1..=12).contains(&month),
+            (Some(month), Some(day)) => (1..=12).contains(&month) && (1..=31).contains(&day),
+        };
+        if !valid {
+            return Err(crate::error::Error::Other(format!(
+                "Invalid date: year {}, month {:?}, day {:?}",
+                year, month, day
+            )));
+        }
+        Ok(Date { year, month, day })
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "-{:02}", month)?;
+        }
+        if let Some(day) = self.day {
+            write!(f, "-{:02}", day)?;
+        }
+        Ok(())
+    }
+}
+
+impl ParamValue for Date {}
+impl DateValue for Date {}
+
 /// A parameter that specifies a game that the card appears in.
 ///
 /// `GameValue` is the argument type for [`game()`] and [`in_game()`].
@@ -647,12 +990,37 @@ impl GameValue for crate::card::Game {}
 /// `LanguageValue` is used as an argument to [`language()`] and
 /// [`in_language()`].
 ///
-/// This trait is implemented for all `TextValue` types.
+/// This trait is implemented for the [`Language`][crate::card::Language]
+/// enum and all `TextValue` types.
 pub trait LanguageValue: ParamValue {}
 
 impl<T: TextValue> LanguageValue for T {}
 
+impl ParamValue for crate::card::Language {}
+impl LanguageValue for crate::card::Language {}
+
+/// A parameter that identifies a card's artwork.
+///
+/// `IllustrationValue` is the argument type for [`illustration()`].
+///
+/// This trait is implemented for [`Uuid`][crate::Uuid], which can be taken
+/// from a card's [`illustration_id`][crate::Card::illustration_id], and for
+/// all [`TextValue`] types.
+pub trait IllustrationValue: ParamValue {}
+
+impl<T: TextValue> IllustrationValue for T {}
+
+impl ParamValue for crate::Uuid {}
+impl IllustrationValue for crate::Uuid {}
+
+impl ParamValue for uuid::Uuid {}
+impl IllustrationValue for uuid::Uuid {}
+
 mod functions {
+    use std::collections::HashSet;
+
+    use once_cell::sync::OnceCell;
+
     use super::*;
     use crate::search::query::Query;
 
@@ -681,17 +1049,23 @@ mod functions {
         color_identity_count => ColorIdentity: NumericValue,
         #[doc = "The type line of this card."]
         type_line => Type: TextOrRegexValue,
-        #[doc = "The updated oracle text of this card."]
+        #[doc = "The updated oracle text of this card, without reminder text."]
+        #[doc = ""]
+        #[doc = "Text is quoted, so `oracle_text(\"draw a card\")` searches for"]
+        #[doc = "`oracle:\"draw a card\"`. A [`Regex`] is surrounded by slashes instead, so"]
+        #[doc = "`oracle_text(Regex::from(\"^{T}:\"))` searches for `oracle:/^{T}:/`."]
         oracle_text => Oracle: TextOrRegexValue,
-        #[doc = "The updated oracle text of this card, including reminder text."]
+        #[doc = "The updated oracle text of this card, including reminder text. Searches"]
+        #[doc = "for `fulloracle:`, and otherwise works like [`oracle_text()`]."]
         full_oracle_text => FullOracle: TextOrRegexValue,
         #[doc = "Keyword ability that this card has."]
         keyword => Keyword: TextValue,
-        #[doc = "The mana cost of this card."]
-        mana => Mana: ColorValue,
         #[doc = "The devotion granted by this permanent. See [`Devotion`]."]
         devotion => Devotion: DevotionValue,
         #[doc = "The colors of mana produced by this card."]
+        #[doc = ""]
+        #[doc = "`produces(gte(Colors::AZORIUS))` searches for `produces>=WU`, matching cards"]
+        #[doc = "that produce at least white and blue mana."]
         produces => Produces: ColorValue,
         #[doc = "The rarity of this printing."]
         rarity => Rarity: RarityValue,
@@ -702,7 +1076,7 @@ mod functions {
         #[doc = "Was the card printed in this set?"]
         in_set => InSet: SetValue,
         #[doc = "The card's collector number."]
-        collector_number => Number: NumericValue,
+        collector_number => Number: CollectorNumberValue,
         #[doc = "The block of this card. Works with any set grouped in the same block."]
         block => Block: SetValue,
         #[doc = "The type of set this printing is in."]
@@ -721,14 +1095,19 @@ mod functions {
         cheapest => Cheapest: CurrencyValue,
         #[doc = "The artist who illustrated this card."]
         artist => Artist: TextValue,
+        #[doc = "The printings with this artwork. See [`Card::illustration_id`][crate::Card::illustration_id]."]
+        illustration => Illustration: IllustrationValue,
         #[doc = "The flavor text of this printing."]
         flavor_text => Flavor: TextOrRegexValue,
         #[doc = "The type of watermark on this printing."]
-        watermark => Watermark: TextValue,
+        watermark => Watermark: WatermarkValue,
         #[doc = "The border color of this printing."]
         border_color => BorderColor: BorderColorValue,
         #[doc = "The card frame of this printing, related to the year of the print."]
         frame => Frame: FrameValue,
+        #[doc = "A frame effect of this printing. Searches for the same `frame:` keyword as"]
+        #[doc = "[`frame()`], but only accepts frame effects."]
+        frame_effect => Frame: FrameEffectValue,
         #[doc = "The date this printing was released."]
         date => Date: DateValue,
         #[doc = "This printing is available in the specified game."]
@@ -743,6 +1122,92 @@ mod functions {
         name => Name: TextOrRegexValue,
     }
 
+    /// The mana cost of this card, checked to be a valid mana cost before
+    /// building the query. See [`ManaValue`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::search::prelude::*;
+    /// assert_eq!(mana("{2}{W}{W}").unwrap().to_string(), "mana:{2}{W}{W}");
+    /// assert_eq!(mana(gte("{G}{G}")).unwrap().to_string(), "mana>={G}{G}");
+    /// assert!(mana("{Q}{Q}").is_err());
+    /// ```
+    pub fn mana(cost: impl ManaValue) -> crate::Result<Query> {
+        let kind = ValueKind(ValueKindImpl::Mana);
+        Ok(Query::Param(cost.check_mana()?.into_param(kind)))
+    }
+
+    /// Keyword ability that this card has, checked against Scryfall's
+    /// [keyword catalogs][crate::catalog::Catalog::keyword_abilities] before
+    /// building the query. The catalogs are fetched once and cached.
+    ///
+    /// Returns an error if the keyword is not in any of the catalogs, or if
+    /// the catalogs could not be fetched. To skip the check when Scryfall
+    /// can't be reached, use [`keyword_lenient()`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::search::prelude::*;
+    /// assert_eq!(
+    ///     keyword_strict("flying").unwrap().to_string(),
+    ///     "keyword:\"flying\""
+    /// );
+    /// assert!(keyword_strict("fyling").is_err());
+    /// ```
+    pub fn keyword_strict(value: &str) -> crate::Result<Query> {
+        check_keyword(value, known_keywords()?)
+    }
+
+    /// Like [`keyword_strict()`], but if the keyword catalogs could not be
+    /// fetched, the keyword is not checked. Keywords that aren't in the
+    /// catalogs are still an error when they could be fetched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::search::prelude::*;
+    /// // Works whether or not Scryfall can be reached.
+    /// assert_eq!(
+    ///     keyword_lenient("Landfall").unwrap().to_string(),
+    ///     "keyword:\"Landfall\""
+    /// );
+    /// ```
+    pub fn keyword_lenient(value: &str) -> crate::Result<Query> {
+        match known_keywords() {
+            Ok(keywords) => check_keyword(value, keywords),
+            Err(_) => Ok(keyword(value)),
+        }
+    }
+
+    fn check_keyword(value: &str, keywords: &HashSet<String>) -> crate::Result<Query> {
+        if keywords.contains(&value.trim().to_lowercase()) {
+            Ok(keyword(value))
+        } else {
+            Err(crate::error::Error::Other(format!(
+                "Unknown keyword: {}",
+                value
+            )))
+        }
+    }
+
+    /// The lowercase names of all keyword abilities, keyword actions and
+    /// ability words.
+    fn known_keywords() -> crate::Result<&'static HashSet<String>> {
+        use crate::catalog::{Catalog, CatalogKind};
+
+        static KEYWORDS: OnceCell<HashSet<String>> = OnceCell::new();
+        KEYWORDS.get_or_try_init(|| {
+            let mut keywords = HashSet::new();
+            for kind in [
+                CatalogKind::KeywordAbilities,
+                CatalogKind::KeywordActions,
+                CatalogKind::AbilityWords,
+            ] {
+                keywords.extend(Catalog::get(kind)?.iter().map(|k| k.to_lowercase()));
+            }
+            Ok(keywords)
+        })
+    }
+
     macro_rules! numeric_value_fns {
         ($(
             $(#[$($attr:meta)*])*
@@ -761,14 +1226,29 @@ mod functions {
 
     numeric_value_fns! {
         #[doc = "The card's power, if it is a creature or vehicle. '*' and 'X' count as 0."]
+        #[doc = ""]
+        #[doc = "`power(gt(3))` searches for `power>3`, and"]
+        #[doc = "`power(eq(NumProperty::Toughness))` searches for `power=toughness`."]
         power => Power,
         #[doc = "The card's toughness, if it is a creature or vehicle. '*' and 'X' count as 0."]
+        #[doc = ""]
+        #[doc = "`toughness(lte(1))` searches for `toughness<=1`, and"]
+        #[doc = "`toughness(gt(NumProperty::Power))` searches for `toughness>power`."]
         toughness => Toughness,
         #[doc = "The card's power plus its toughness."]
+        #[doc = ""]
+        #[doc = "`pow_tou(10)` searches for `powtou:10`, and"]
+        #[doc = "`pow_tou(eq(NumProperty::Cmc))` searches for `powtou=cmc`."]
         pow_tou => PowTou,
         #[doc = "The card's loyalty, if it is a planeswalker. 'X' counts as 0."]
+        #[doc = ""]
+        #[doc = "`loyalty(gte(5))` searches for `loyalty>=5`, and"]
+        #[doc = "`loyalty(lt(NumProperty::Cmc))` searches for `loyalty<cmc`."]
         loyalty => Loyalty,
         #[doc = "The converted mana cost of this card."]
+        #[doc = ""]
+        #[doc = "`cmc(neq(4))` searches for `cmc!=4`, and"]
+        #[doc = "`cmc(gt(NumProperty::Power))` searches for `cmc>power`."]
         cmc => Cmc,
         #[doc = "The number of artists credited for this printing."]
         artist_count => ArtistCount,
@@ -791,6 +1271,9 @@ mod functions {
         #[doc = "The number of sets this card has appeared in, counting paper only."]
         paper_set_count => PaperSetCount,
         #[doc = "The year this card was released."]
+        #[doc = ""]
+        #[doc = "`year(1995)` searches for `year:1995`, and `year(gte(2020))` searches for"]
+        #[doc = "`year>=2020`. To search by a full date, use [`date()`]."]
         year => Year,
     }
 }
@@ -0,0 +1,182 @@
+//! Checks value parameters against a [`Card`] in memory, for
+//! [`Query::matches`][crate::search::query::Query::matches].
+use std::cmp::Ordering;
+
+use chrono::Datelike;
+
+use super::{Currency, NumProperty, ValueKind, ValueKindImpl};
+use crate::card::{Card, Color, Colors, Rarity};
+use crate::search::param::compare::CompareOp;
+
+impl ValueKind {
+    /// Checks if `card` matches this kind of parameter with `value`, as it
+    /// is written in the query. `op` is `None` for `kind:value`.
+    ///
+    /// Returns `None` if the parameter or its value can't be checked locally.
+    pub(in crate::search) fn matches(
+        self,
+        op: Option<CompareOp>,
+        value: &str,
+        card: &Card,
+    ) -> Option<bool> {
+        match self.0 {
+            ValueKindImpl::Name => text_matches(op, value, names(card)),
+            ValueKindImpl::Type => text_matches(op, value, type_lines(card)),
+            ValueKindImpl::Oracle => text_matches(op, value, oracle_texts(card)),
+            ValueKindImpl::Keyword if op.is_none() => Some(card.has_keyword(unquote(value))),
+            ValueKindImpl::Color => {
+                colors_match(op.unwrap_or(CompareOp::Gte), value, card_colors(card))
+            },
+            // Unlike for colors, `produces:c` means producing colorless mana.
+            ValueKindImpl::Produces
+                if matches!(op, None | Some(CompareOp::Gte))
+                    && unquote(value).eq_ignore_ascii_case("c") =>
+            {
+                Some(card.produces_colorless())
+            },
+            ValueKindImpl::Produces => {
+                colors_match(op.unwrap_or(CompareOp::Gte), value, card.produces())
+            },
+            ValueKindImpl::ColorIdentity => colors_match(
+                op.unwrap_or(CompareOp::Lte),
+                value,
+                card.color_identity_colors(),
+            ),
+            ValueKindImpl::Rarity => {
+                let rarity = unquote(value).parse::<Rarity>().ok()?;
+                Some(compare(
+                    op.unwrap_or(CompareOp::Eq),
+                    card.rarity.cmp(&rarity),
+                ))
+            },
+            ValueKindImpl::Set if op.is_none() => {
+                Some(card.set.as_str().eq_ignore_ascii_case(unquote(value)))
+            },
+            ValueKindImpl::NumericComparable(property) => {
+                let lhs = number(card, property)?;
+                let rhs = match value.parse::<f64>() {
+                    Ok(n) => Some(n),
+                    Err(_) => number(card, value.parse().ok()?)?,
+                };
+                match (lhs, rhs) {
+                    (Some(lhs), Some(rhs)) => Some(
+                        lhs.partial_cmp(&rhs)
+                            .is_some_and(|o| compare(op.unwrap_or(CompareOp::Eq), o)),
+                    ),
+                    _ => Some(false),
+                }
+            },
+            _ => None,
+        }
+    }
+}
+
+fn compare(op: CompareOp, ordering: Ordering) -> bool {
+    match op {
+        CompareOp::Lte => ordering != Ordering::Greater,
+        CompareOp::Lt => ordering == Ordering::Less,
+        CompareOp::Gte => ordering != Ordering::Less,
+        CompareOp::Gt => ordering == Ordering::Greater,
+        CompareOp::Eq => ordering == Ordering::Equal,
+        CompareOp::Neq => ordering != Ordering::Equal,
+    }
+}
+
+fn unquote(value: &str) -> &str {
+    value.trim_matches('"')
+}
+
+/// Text parameters match if any of `texts` contains the value, ignoring
+/// case. Regular expressions aren't supported.
+fn text_matches<'a>(
+    op: Option<CompareOp>,
+    value: &str,
+    mut texts: impl Iterator<Item = &'a str>,
+) -> Option<bool> {
+    if op.is_some() || value.starts_with('/') {
+        return None;
+    }
+    let value = unquote(value).to_lowercase();
+    Some(texts.any(|text| text.to_lowercase().contains(&value)))
+}
+
+fn names(card: &Card) -> impl Iterator<Item = &str> {
+    std::iter::once(card.name.as_str()).chain(card.faces().into_iter().map(|f| f.name.as_str()))
+}
+
+fn type_lines(card: &Card) -> impl Iterator<Item = &str> {
+    std::iter::once(card.type_line.as_str())
+        .chain(card.faces().into_iter().map(|f| f.type_line.as_str()))
+}
+
+fn oracle_texts(card: &Card) -> impl Iterator<Item = &str> {
+    card.oracle_text.as_deref().into_iter().chain(
+        card.faces()
+            .into_iter()
+            .filter_map(|f| f.oracle_text.as_deref()),
+    )
+}
+
+fn card_colors(card: &Card) -> Colors {
+    card.colors
+        .iter()
+        .chain(card.faces().into_iter().filter_map(|f| f.colors.as_ref()))
+        .flatten()
+        .copied()
+        .collect()
+}
+
+/// Compares `have` against colors written like `wu`, `c` for colorless, or
+/// `m` for multicolored.
+fn colors_match(op: CompareOp, value: &str, have: Colors) -> Option<bool> {
+    let value = unquote(value);
+    if value.eq_ignore_ascii_case("m") {
+        return match op {
+            CompareOp::Gte => Some(have.is_multicolored()),
+            _ => None,
+        };
+    }
+    let want = value
+        .chars()
+        .map(|c| c.to_string().parse::<Color>().ok())
+        .collect::<Option<Colors>>()?;
+    let op = match op {
+        // `c:c` matches colorless cards, not every card.
+        CompareOp::Gte if want.is_colorless() => CompareOp::Eq,
+        op => op,
+    };
+    Some(match op {
+        CompareOp::Eq => have == want,
+        CompareOp::Neq => have != want,
+        CompareOp::Gte => want.is_subset(have),
+        CompareOp::Gt => want.is_subset(have) && have != want,
+        CompareOp::Lte => have.is_subset(want),
+        CompareOp::Lt => have.is_subset(want) && have != want,
+    })
+}
+
+/// The value of `property` for `card`. Returns `None` if the property can't
+/// be checked locally, and `Some(None)` if this card doesn't have it.
+fn number(card: &Card, property: NumProperty) -> Option<Option<f64>> {
+    let stat = |top: &Option<String>, face: fn(&crate::card::CardFace) -> &Option<String>| {
+        std::iter::once(top)
+            .chain(card.faces().into_iter().map(face))
+            .flatten()
+            .find_map(|s| s.parse::<f64>().ok())
+    };
+    let power = || stat(&card.power, |f| &f.power);
+    let toughness = || stat(&card.toughness, |f| &f.toughness);
+    Some(match property {
+        NumProperty::Power => power(),
+        NumProperty::Toughness => toughness(),
+        NumProperty::PowTou => power().zip(toughness()).map(|(p, t)| p + t),
+        NumProperty::Loyalty => stat(&card.loyalty, |f| &f.loyalty),
+        NumProperty::Cmc => Some(card.cmc.into()),
+        NumProperty::Year => Some(card.released_at.year().into()),
+        NumProperty::Usd => card.prices.value_in(Currency::Usd),
+        NumProperty::UsdFoil => card.prices.value_in(Currency::UsdFoil),
+        NumProperty::Eur => card.prices.value_in(Currency::Eur),
+        NumProperty::Tix => card.prices.value_in(Currency::Tix),
+        _ => return None,
+    })
+}
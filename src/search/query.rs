@@ -2,12 +2,18 @@
 //! of [`Param`]s.
 
 use std::fmt;
+use std::ops::Not;
+use std::str::FromStr;
 
 use url::Url;
 
+use crate::card::Card;
+use crate::search::param::criteria::{CardIs, PrintingIs};
 use crate::search::param::Param;
 use crate::search::Search;
 
+mod parser;
+
 /// A search query, composed of search parameters and boolean operations.
 ///
 /// `Query` is an expression tree, supporting `AND`, `OR`, and `NOT` operations,
@@ -37,6 +43,12 @@ use crate::search::Search;
 ///
 /// For information on search parameters, see the
 /// [`param`][crate::search::param] module.
+///
+/// The `Display` impl writes the query in Scryfall syntax, exactly as it is
+/// sent to Scryfall. `FromStr` reads it back with [`Query::parse`], so
+/// `query.to_string().parse()` gives back an equivalent query. Groups with a
+/// single sub-query and [`Custom`][Query::Custom] fragments are parsed into
+/// their contents.
 #[derive(Clone, PartialEq, Debug)]
 pub enum Query {
     /// The returned cards must match all of the sub-queries.
@@ -78,6 +90,14 @@ impl fmt::Display for Query {
     }
 }
 
+impl FromStr for Query {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        Query::parse(s)
+    }
+}
+
 impl Search for Query {
     fn write_query(&self, url: &mut Url) -> crate::Result<()> {
         super::write_query_string(self, url)
@@ -125,8 +145,199 @@ impl Query {
         #[doc = "Combines `self` with `other` using the boolean OR operation."]
         or(Or),
     }
+
+    /// Negates this query, like [`not()`]. A single parameter is written as
+    /// `-param`, and groups as `-(...)`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use scryfall::search::prelude::*;
+    /// assert_eq!(name("x").negated().to_string(), "-name:\"x\"");
+    /// assert_eq!(
+    ///     name("x").or(name("y")).negated().to_string(),
+    ///     "-(name:\"x\" OR name:\"y\")"
+    /// );
+    /// ```
+    pub fn negated(self) -> Self {
+        not(self)
+    }
+
+    /// Creates a [`Custom`][Query::Custom] query from a fragment of raw
+    /// [Scryfall syntax](https://scryfall.com/docs/syntax), for parameters
+    /// this crate doesn't support yet. The fragment is wrapped in parentheses
+    /// when written, so it can be combined with other queries safely even if
+    /// it contains spaces or `OR`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use scryfall::search::prelude::*;
+    /// let query = type_line("creature").and(Query::raw("otag:removal or otag:burn"));
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "(type:\"creature\" AND (otag:removal or otag:burn))"
+    /// );
+    /// ```
+    pub fn raw(fragment: impl Into<String>) -> Self {
+        Query::Custom(fragment.into())
+    }
+
+    /// Parses a query written in [Scryfall syntax](https://scryfall.com/docs/syntax)
+    /// into a `Query` tree, so that it can be inspected or modified before
+    /// searching.
+    ///
+    /// Terms are combined with `AND` (which may be left implicit) and `OR`,
+    /// grouped with parentheses, and negated with `-`. Unrecognized keywords
+    /// are reported as errors.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use scryfall::search::prelude::*;
+    /// use scryfall::card::Color;
+    /// let query = Query::parse("t:\"goblin\" (c:R OR c:B) -is:vanilla").unwrap();
+    /// assert_eq!(
+    ///     query,
+    ///     type_line("goblin")
+    ///         .and(color(Color::Red).or(color(Color::Black)))
+    ///         .and(not(CardIs::Vanilla))
+    /// );
+    ///
+    /// assert!(Query::parse("foo:bar").is_err());
+    /// ```
+    pub fn parse(input: &str) -> crate::Result<Self> {
+        parser::parse(input)
+    }
+
+    /// Checks if `card` matches this query without asking Scryfall, for
+    /// filtering cards that are already in memory, such as
+    /// [bulk data][crate::bulk].
+    ///
+    /// Only some parameters can be checked locally:
+    /// - [`exact()`][crate::search::param::exact], [`name()`], [`type_line()`]
+    ///   and [`oracle_text()`], with text but not regular expressions. Text
+    ///   matches if it's contained in the card or any of its faces, ignoring
+    ///   case.
+    /// - [`keyword()`], matching one of the card's
+    ///   [`keywords`][crate::Card::keywords] exactly, ignoring case.
+    /// - [`color()`], [`color_identity()`] and [`produces()`], with colors
+    ///   such as `wu`, `c` for colorless, or `m` for multicolored.
+    /// - [`rarity()`] and [`set()`].
+    /// - [`power()`], [`toughness()`], [`pow_tou()`], [`loyalty()`], [`cmc()`],
+    ///   [`year()`] and prices, including comparisons against one another
+    ///   with [`NumProperty`].
+    ///
+    /// A query only matches if it's known to match, so any part of it that
+    /// can't be checked, including [criteria][crate::search::param::criteria]
+    /// and [`Custom`][Query::Custom] queries, doesn't match either way, even
+    /// if it is negated. Results may not be exactly the same as Scryfall's.
+    ///
+    /// [`name()`]: crate::search::param::value::name
+    /// [`type_line()`]: crate::search::param::value::type_line
+    /// [`oracle_text()`]: crate::search::param::value::oracle_text
+    /// [`keyword()`]: crate::search::param::value::keyword
+    /// [`color()`]: crate::search::param::value::color
+    /// [`color_identity()`]: crate::search::param::value::color_identity
+    /// [`produces()`]: crate::search::param::value::produces
+    /// [`rarity()`]: crate::search::param::value::rarity
+    /// [`set()`]: crate::search::param::value::set
+    /// [`power()`]: crate::search::param::value::power
+    /// [`toughness()`]: crate::search::param::value::toughness
+    /// [`pow_tou()`]: crate::search::param::value::pow_tou
+    /// [`loyalty()`]: crate::search::param::value::loyalty
+    /// [`cmc()`]: crate::search::param::value::cmc
+    /// [`year()`]: crate::search::param::value::year
+    /// [`NumProperty`]: crate::search::param::value::NumProperty
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use scryfall::search::prelude::*;
+    /// # fn main() -> scryfall::Result<()> {
+    /// let query = type_line("goblin").and(cmc(lte(2)));
+    /// let cheap_goblins = scryfall::bulk::oracle_cards()?
+    ///     .filter_map(Result::ok)
+    ///     .filter(|card| query.matches(card))
+    ///     .collect::<Vec<_>>();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn matches(&self, card: &Card) -> bool {
+        self.matches_known(card).unwrap_or(false)
+    }
+
+    /// Like [`matches`][Query::matches], but returns `None` if it isn't
+    /// known whether `card` matches.
+    fn matches_known(&self, card: &Card) -> Option<bool> {
+        match self {
+            Query::And(exprs) => all_or_any(exprs, card, false),
+            Query::Or(exprs) => all_or_any(exprs, card, true),
+            Query::Not(expr) => expr.matches_known(card).map(|matches| !matches),
+            Query::Param(param) => param.matches(card),
+            Query::Custom(_) => None,
+        }
+    }
+}
+
+/// Checks `exprs` against `card` for [`Query::matches`]. A result of `decisive`
+/// from any sub-query is the result of the whole group, so `decisive` is
+/// `false` for `AND` and `true` for `OR`.
+fn all_or_any(exprs: &[Query], card: &Card, decisive: bool) -> Option<bool> {
+    let mut known = true;
+    for expr in exprs {
+        match expr.matches_known(card) {
+            Some(matches) if matches == decisive => return Some(decisive),
+            Some(_) => {},
+            None => known = false,
+        }
+    }
+    known.then_some(!decisive)
+}
+
+impl Not for Query {
+    type Output = Query;
+
+    /// Negates this query. See [`not()`].
+    fn not(self) -> Query {
+        not(self)
+    }
+}
+
+/// Implements `and`, `or` and [`Not`] for types that can be turned into a
+/// [`Query`], so they can be combined without converting them first.
+macro_rules! impl_query_ops {
+    ($($Ty:ty),* $(,)?) => {
+        $(
+            impl $Ty {
+                /// Combines `self` with `other` using the boolean AND
+                /// operation. See [`Query::and`].
+                pub fn and(self, other: impl Into<Query>) -> Query {
+                    Query::from(self).and(other)
+                }
+
+                /// Combines `self` with `other` using the boolean OR
+                /// operation. See [`Query::or`].
+                pub fn or(self, other: impl Into<Query>) -> Query {
+                    Query::from(self).or(other)
+                }
+
+                /// Negates `self`. See [`Query::negated`].
+                pub fn negated(self) -> Query {
+                    not(self)
+                }
+            }
+
+            impl Not for $Ty {
+                type Output = Query;
+
+                /// Negates this value. See [`not()`].
+                fn not(self) -> Query {
+                    not(self)
+                }
+            }
+        )*
+    };
 }
 
+impl_query_ops!(Param, CardIs, PrintingIs);
+
 /// Negates the specified `query`.
 pub fn not(query: impl Into<Query>) -> Query {
     match query.into() {
@@ -140,6 +351,168 @@ mod tests {
     use super::*;
     use crate::search::prelude::*;
 
+    #[test]
+    fn raw_fragments() {
+        let query = Query::Or(vec![
+            Query::raw("art:squirrel"),
+            cmc(1).and(Query::raw("new:art unique:art")),
+        ]);
+        assert_eq!(
+            query.to_string(),
+            "((art:squirrel) OR (cmc:1 AND (new:art unique:art)))"
+        );
+        // Raw fragments can't break out of their parentheses.
+        assert_eq!(
+            not(Query::raw("t:elf OR t:goblin")).to_string(),
+            "-(t:elf OR t:goblin)"
+        );
+    }
+
+    #[test]
+    fn matches_cards_locally() {
+        use crate::card::{Color, Colors, Multicolored, Rarity};
+
+        let card = crate::card::tests::card_with(|json| {
+            json["name"] = "Goblin Guide".into();
+            json["type_line"] = "Creature — Goblin Scout".into();
+            json["oracle_text"] = "Haste".into();
+            json["keywords"] = serde_json::json!(["Haste"]);
+            json["produced_mana"] = serde_json::json!(["C", "R"]);
+            json["colors"] = serde_json::json!(["R"]);
+            json["color_identity"] = serde_json::json!(["R"]);
+            json["power"] = "2".into();
+            json["toughness"] = "2".into();
+            json["rarity"] = "rare".into();
+            json["set"] = "zen".into();
+            json["released_at"] = "2009-10-02".into();
+        });
+
+        let matching = vec![
+            exact("goblin guide"),
+            name("guide"),
+            type_line("goblin").and(oracle_text("haste")),
+            keyword("HASTE"),
+            produces(Colors::RED),
+            produces(Colors::colorless()),
+            color(Color::Red),
+            color(eq(Colors::RED)),
+            color_identity(Colors::GRUUL),
+            not(color(Multicolored)),
+            rarity(gte(Rarity::Uncommon)),
+            set("ZEN"),
+            cmc(1),
+            cmc(lte(2)),
+            power(gt(1)).and(toughness(2)),
+            power(eq(NumProperty::Toughness)),
+            pow_tou(4),
+            year(2009),
+            type_line("elf").or(type_line("goblin")),
+            Query::default(),
+        ];
+        for query in matching {
+            assert!(query.matches(&card), "{} should match", query);
+        }
+
+        let not_matching = vec![
+            name("bolt"),
+            keyword("flying"),
+            produces(Colors::GRUUL),
+            color(Colors::BOROS),
+            color(Colors::colorless()),
+            color_identity(Colors::WHITE),
+            rarity(Rarity::Mythic),
+            cmc(gt(1)),
+            toughness(neq(2)),
+            loyalty(3),
+            type_line("goblin").and(not(oracle_text("haste"))),
+            Query::Or(vec![]),
+            // These can't be checked locally.
+            name(Regex::from("^goblin")),
+            CardIs::Vanilla.into(),
+            not(CardIs::Vanilla),
+            Query::raw("t:goblin"),
+        ];
+        for query in not_matching {
+            assert!(!query.matches(&card), "{} shouldn't match", query);
+        }
+        assert!(name("guide").or(CardIs::Vanilla).matches(&card));
+        assert!(!name("bolt").or(CardIs::Vanilla).matches(&card));
+    }
+
+    #[test]
+    fn combinations_flatten() {
+        let (a, b, c) = (cmc(4), name("Yargle"), set("dom"));
+        assert_eq!(
+            a.clone().and(b.clone()).and(c.clone()),
+            Query::And(vec![a.clone(), b.clone(), c.clone()])
+        );
+        assert_eq!(
+            a.clone().and(b.clone().and(c.clone())),
+            Query::And(vec![a.clone(), b.clone(), c.clone()])
+        );
+        assert_eq!(
+            a.clone().or(b.clone()).or(c.clone()).to_string(),
+            "(cmc:4 OR name:\"Yargle\" OR set:\"dom\")"
+        );
+        assert_eq!(
+            a.clone().and(b.clone().or(c.clone())).to_string(),
+            "(cmc:4 AND (name:\"Yargle\" OR set:\"dom\"))"
+        );
+        assert_eq!(
+            CardIs::Vanilla.and(PrintingIs::Reprint).not(),
+            not(Query::And(vec![
+                CardIs::Vanilla.into(),
+                PrintingIs::Reprint.into()
+            ]))
+        );
+        assert_eq!(!!a.clone(), a);
+        assert_eq!((!CardIs::Funny).to_string(), "-is:funny");
+    }
+
+    #[test]
+    fn negated_params() {
+        assert_eq!(name("x").negated().to_string(), "-name:\"x\"");
+        assert_eq!(cmc(gt(3)).negated().to_string(), "-cmc>3");
+        assert_eq!(CardIs::Vanilla.negated().to_string(), "-is:vanilla");
+        assert_eq!(
+            name("x").and(cmc(3)).negated().to_string(),
+            "-(name:\"x\" AND cmc:3)"
+        );
+        assert_eq!(name("x").negated().negated(), name("x"));
+        assert_eq!(name("x").negated(), Query::Not(Box::new(name("x"))));
+    }
+
+    #[test]
+    fn display_round_trips() {
+        use crate::card::{Color, Rarity};
+
+        let queries = vec![
+            Query::default(),
+            name("lightning").and(not(name("bolt"))),
+            exact("Black Lotus").or(exact("Mox Pearl")),
+            type_line(Regex::from("^legendary.*elf")),
+            power(gte(NumProperty::Toughness)).and(cmc(lt(3))),
+            color(Color::Green)
+                .or(color_identity(lte(Color::Blue)))
+                .and(rarity(gt(Rarity::Uncommon))),
+            not(PrintingIs::Reprint).and(CardIs::Commander),
+            in_rarity(Rarity::Common).and(set("m21")),
+            Query::Custom("t:elf".to_string()).or(Query::raw("-t:goblin")),
+        ];
+
+        for query in queries {
+            let text = query.to_string();
+            let parsed: Query = text.parse().unwrap();
+            let reparsed: Query = parsed.to_string().parse().unwrap();
+            assert_eq!(reparsed, parsed, "{}", text);
+        }
+        // Structure is kept exactly when there are no one-element groups or
+        // custom fragments.
+        let query = type_line("creature").and(power(gt(3)).or(not(toughness(4))));
+        assert_eq!(query.to_string().parse::<Query>().unwrap(), query);
+        assert_eq!("()".parse::<Query>().unwrap(), Query::default());
+    }
+
     #[test]
     fn even_power() -> crate::Result<()> {
         // Scryfall doesn't support "power:even", so let's do it manually.
@@ -155,9 +528,9 @@ mod tests {
             .and_then(|pow| pow.parse().ok())
             .unwrap_or(0);
 
-        let query = normal_creatures.clone().and(Query::Or(
-            (0..=highest_power).map(|pow| power(pow)).collect(),
-        ));
+        let query = normal_creatures
+            .clone()
+            .and(Query::Or((0..=highest_power).map(power).collect()));
 
         // There are at least 1000 cards with even power.
         assert!(query.search().unwrap().size_hint().0 > 1000);
@@ -0,0 +1,343 @@
+//! A recursive descent parser for queries written in
+//! [Scryfall syntax](https://scryfall.com/docs/syntax).
+//!
+//! The grammar handled here is:
+//!
+//! ```text
+//! or_expr  := and_expr ("OR" and_expr)*
+//! and_expr := unary ("AND"? unary)*
+//! unary    := "-" unary | atom
+//! atom     := "(" or_expr ")" | "!" value | term
+//! term     := keyword op value | value
+//! ```
+use crate::error::Error;
+use crate::search::param::compare::CompareOp;
+use crate::search::param::criteria::{CardIs, Criterion, PrintingIs};
+use crate::search::param::value::ValueKind;
+use crate::search::param::Param;
+use crate::search::query::{not, Query};
+
+pub(super) fn parse(input: &str) -> crate::Result<Query> {
+    let mut parser = Parser { input, pos: 0 };
+    let query = parser.or_expr()?;
+    parser.skip_whitespace();
+    match parser.peek() {
+        None => Ok(query),
+        Some(')') => Err(parser.error("Unbalanced parentheses")),
+        Some(_) => Err(parser.error("Unexpected input")),
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, message: &str) -> Error {
+        Error::Other(format!(
+            "{} at position {} of query: {}",
+            message, self.pos, self.input
+        ))
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    /// Consumes the keyword `word`, ignoring case, if it is the next word in
+    /// the input.
+    fn eat_keyword(&mut self, word: &str) -> bool {
+        let rest = self.rest();
+        let matches = rest
+            .get(..word.len())
+            .is_some_and(|s| s.eq_ignore_ascii_case(word))
+            && match rest[word.len()..].chars().next() {
+                Some(c) => c.is_whitespace() || c == '(' || c == ')',
+                None => true,
+            };
+        if matches {
+            self.pos += word.len();
+        }
+        matches
+    }
+
+    fn or_expr(&mut self) -> crate::Result<Query> {
+        let mut exprs = vec![self.and_expr()?];
+        loop {
+            self.skip_whitespace();
+            if !self.eat_keyword("or") {
+                break;
+            }
+            exprs.push(self.and_expr()?);
+        }
+        Ok(collapse(exprs, Query::Or))
+    }
+
+    fn and_expr(&mut self) -> crate::Result<Query> {
+        let mut exprs = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                None | Some(')') => break,
+                _ if self.eat_keyword("or") => {
+                    // Put the `OR` back for `or_expr` to handle.
+                    self.pos -= "or".len();
+                    break;
+                },
+                _ => {},
+            }
+            if !exprs.is_empty() && self.eat_keyword("and") {
+                self.skip_whitespace();
+            }
+            exprs.push(self.unary()?);
+        }
+        if exprs.is_empty() {
+            return Err(self.error("Expected a search term"));
+        }
+        Ok(collapse(exprs, Query::And))
+    }
+
+    fn unary(&mut self) -> crate::Result<Query> {
+        self.skip_whitespace();
+        if self.peek() == Some('-') {
+            self.bump();
+            return Ok(not(self.unary()?));
+        }
+        self.atom()
+    }
+
+    fn atom(&mut self) -> crate::Result<Query> {
+        match self.peek() {
+            Some('(') => {
+                self.bump();
+                self.skip_whitespace();
+                if self.peek() == Some(')') {
+                    // An empty group, as written for an empty `AND`.
+                    self.bump();
+                    return Ok(Query::default());
+                }
+                let query = self.or_expr()?;
+                self.skip_whitespace();
+                if self.bump() != Some(')') {
+                    return Err(self.error("Unbalanced parentheses"));
+                }
+                Ok(query)
+            },
+            Some('!') => {
+                self.bump();
+                let name = self.value()?;
+                Ok(Query::Param(Param::exact(name.trim_matches('"'))))
+            },
+            _ => self.term(),
+        }
+    }
+
+    fn term(&mut self) -> crate::Result<Query> {
+        let start = self.pos;
+        let keyword_len = self
+            .rest()
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or_else(|| self.rest().len());
+        let keyword = &self.rest()[..keyword_len];
+        self.pos += keyword_len;
+
+        let op = match self.compare_op() {
+            Some(op) if !keyword.is_empty() => op,
+            _ => {
+                // Not a `keyword:value` pair, so this is part of the name.
+                self.pos = start;
+                let value = self.value()?;
+                return Ok(Query::Param(Param::value(
+                    ValueKind::from_keyword("name", &value).unwrap(),
+                    value,
+                )));
+            },
+        };
+        let value = self.value()?;
+
+        if op.is_none() {
+            if keyword.eq_ignore_ascii_case("not") {
+                let criterion = criterion("is", &value)
+                    .ok_or_else(|| self.error("Unknown search criterion"))?;
+                return Ok(not(criterion));
+            }
+            if let Some(criterion) = criterion(keyword, &value) {
+                return Ok(criterion.into());
+            }
+        }
+
+        let kind = ValueKind::from_keyword(keyword, &value).ok_or_else(|| {
+            Error::Other(format!(
+                "Unknown search keyword {:?} in query: {}",
+                keyword, self.input
+            ))
+        })?;
+        Ok(Query::Param(match op {
+            None => Param::value(kind, value),
+            Some(op) => Param::comparison(kind, op, value),
+        }))
+    }
+
+    /// Parses a comparison operator, where `Some(None)` is the `:` operator.
+    fn compare_op(&mut self) -> Option<Option<CompareOp>> {
+        let ops = [
+            ("<=", Some(CompareOp::Lte)),
+            (">=", Some(CompareOp::Gte)),
+            ("!=", Some(CompareOp::Neq)),
+            ("<", Some(CompareOp::Lt)),
+            (">", Some(CompareOp::Gt)),
+            ("=", Some(CompareOp::Eq)),
+            (":", None),
+        ];
+        let (s, op) = ops.iter().find(|(s, _)| self.rest().starts_with(s))?;
+        self.pos += s.len();
+        Some(*op)
+    }
+
+    /// Parses a quoted string, a regular expression, or a single word. Quotes
+    /// and slashes are kept in the returned value.
+    fn value(&mut self) -> crate::Result<String> {
+        let start = self.pos;
+        match self.peek() {
+            Some(delim @ '"') | Some(delim @ '/') => {
+                self.bump();
+                let mut escaped = false;
+                loop {
+                    match self.bump() {
+                        None => return Err(self.error("Unterminated quote")),
+                        Some(c) if c == delim && !escaped => break,
+                        Some(c) => escaped = delim == '/' && c == '\\' && !escaped,
+                    }
+                }
+            },
+            _ => {
+                while self
+                    .peek()
+                    .is_some_and(|c| !c.is_whitespace() && c != ')' && c != '(')
+                {
+                    self.bump();
+                }
+            },
+        }
+        if self.pos == start {
+            return Err(self.error("Expected a value"));
+        }
+        Ok(self.input[start..self.pos].to_string())
+    }
+}
+
+/// Builds an `AND` or `OR` query, unless there is only one expression.
+fn collapse(mut exprs: Vec<Query>, variant: fn(Vec<Query>) -> Query) -> Query {
+    if exprs.len() == 1 {
+        exprs.pop().unwrap()
+    } else {
+        variant(exprs)
+    }
+}
+
+/// Looks up a criterion such as `is:split` or `has:watermark`. Scryfall
+/// accepts `is:` and `has:` interchangeably.
+fn criterion(keyword: &str, value: &str) -> Option<Criterion> {
+    let swapped = match keyword.to_ascii_lowercase().as_str() {
+        "is" => "has",
+        "has" => "is",
+        _ => keyword,
+    };
+    [keyword, swapped].iter().find_map(|keyword| {
+        let text = format!("{}:{}", keyword, value);
+        text.parse::<CardIs>()
+            .map(Criterion::Card)
+            .or_else(|_| text.parse::<PrintingIs>().map(Criterion::Printing))
+            .ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::card::Rarity;
+    use crate::search::prelude::*;
+
+    #[test]
+    fn parse_terms() {
+        let matrix = vec![
+            ("cmc>=3", cmc(gte(3))),
+            ("cmc:3", cmc(3)),
+            ("pow>toughness", power(gt(NumProperty::Toughness))),
+            ("t:\"goblin\"", type_line("goblin")),
+            ("o:/^{T}:/", oracle_text(Regex::from("^{T}:"))),
+            ("is:split", CardIs::Split.into()),
+            ("has:watermark", PrintingIs::Watermark.into()),
+            ("is:watermark", PrintingIs::Watermark.into()),
+            ("not:reprint", not(PrintingIs::Reprint)),
+            ("cmc:even", CardIs::EvenCmc.into()),
+            ("in:rare", in_rarity(Rarity::Rare)),
+            ("!\"Lightning Bolt\"", exact("Lightning Bolt")),
+            ("\"Lightning Bolt\"", name("Lightning Bolt")),
+        ];
+
+        for (s, query) in matrix {
+            assert_eq!(Query::parse(s).unwrap(), query, "{}", s);
+        }
+    }
+
+    #[test]
+    fn parse_boolean_operators() {
+        let query = Query::parse("t:\"elf\" (c:\"g\" OR -c:\"w\") and cmc<2").unwrap();
+        assert_eq!(
+            query,
+            Query::And(vec![
+                type_line("elf"),
+                Query::Or(vec![color("g"), not(color("w"))]),
+                cmc(lt(2)),
+            ])
+        );
+        // Keywords starting with `or` or `and` are not operators.
+        assert_eq!(
+            Query::parse("oracle:\"draw\" android").unwrap().to_string(),
+            "(oracle:\"draw\" AND name:android)"
+        );
+    }
+
+    #[test]
+    fn parse_round_trip() {
+        let query = Query::And(vec![
+            type_line("creature"),
+            Query::Or(vec![power(gte(5)), not(toughness(lt(2)))]),
+            set("m21"),
+            CardIs::Vanilla.into(),
+        ]);
+        assert_eq!(Query::parse(&query.to_string()).unwrap(), query);
+    }
+
+    #[test]
+    fn parse_errors() {
+        for s in &[
+            "",
+            "(t:elf",
+            "t:elf)",
+            "t:",
+            "o:\"draw",
+            "foo:bar",
+            "not:nothing",
+        ] {
+            assert!(Query::parse(s).is_err(), "{} should not parse", s);
+        }
+    }
+}
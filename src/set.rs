@@ -10,17 +10,19 @@
 mod set_code;
 mod set_type;
 
+use std::convert::TryFrom;
+use std::io::Read;
+
 use chrono::NaiveDate;
 use percent_encoding::{percent_encode, NON_ALPHANUMERIC};
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
 pub use self::set_code::SetCode;
 pub use self::set_type::SetType;
 use crate::card::Card;
 use crate::list::{List, ListIter};
 use crate::uri::Uri;
-use crate::util::SETS_URL;
+use crate::util::{Uuid, SETS_URL};
 
 /// A Set object containing all fields that `scryfall` provides.
 ///
@@ -107,6 +109,24 @@ impl Set {
         Uri::from(url).fetch_iter()
     }
 
+    /// Returns all the sets in the `scryfall` database, newest first. Sets
+    /// without a release date are put at the end.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::set::Set;
+    /// let sets = Set::all_sorted_by_release().unwrap();
+    /// let paper_sets = sets.iter().filter(|set| !set.digital).collect::<Vec<_>>();
+    /// assert!(paper_sets
+    ///     .windows(2)
+    ///     .all(|pair| pair[0].released_at >= pair[1].released_at));
+    /// ```
+    pub fn all_sorted_by_release() -> crate::Result<Vec<Set>> {
+        let mut sets = Set::all()?.collect::<crate::Result<Vec<_>>>()?;
+        sort_by_release(&mut sets);
+        Ok(sets)
+    }
+
     /// Returns a `Set` with the given set code.
     ///
     /// The code can be either the `code` or the `mtgo_code` for the set.
@@ -156,8 +176,171 @@ impl Set {
         Uri::from(SETS_URL.join(&uuid.to_string())?).fetch()
     }
 
-    /// Returns an iterator over the cards of the set.
+    /// Returns an iterator over the cards of the set, following the set's
+    /// `search_uri`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::set::Set;
+    /// let cards = Set::code("inv").unwrap().cards().unwrap();
+    /// assert!(cards.map(Result::unwrap).all(|card| card.set.get() == "inv"));
+    /// ```
     pub fn cards(&self) -> crate::Result<ListIter<Card>> {
         self.search_uri.fetch_iter()
     }
+
+    /// Returns all the sets in the same block as this one, such as the three
+    /// sets of the original Ravnica block, oldest first. Sets that aren't part
+    /// of a block are returned on their own.
+    ///
+    /// To search for the cards of a whole block, use
+    /// [`block`][crate::search::param::value::block].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::set::Set;
+    /// let block = Set::code("rav").unwrap().block_sets().unwrap();
+    /// let codes = block.iter().map(|set| set.code.as_str()).collect::<Vec<_>>();
+    /// assert_eq!(codes, ["rav", "gpt", "dis"]);
+    /// ```
+    pub fn block_sets(&self) -> crate::Result<Vec<Set>> {
+        match &self.block_code {
+            Some(block_code) => Ok(in_block(
+                Set::all()?.collect::<crate::Result<Vec<_>>>()?,
+                block_code,
+            )),
+            None => Ok(vec![self.clone()]),
+        }
+    }
+
+    /// Fetches the set this one belongs to, such as the main set of a promo
+    /// or token set. Returns `None` if this set has no
+    /// [`parent_set_code`][Set::parent_set_code].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::set::Set;
+    /// let tokens = Set::code("twar").unwrap();
+    /// assert_eq!(tokens.parent_set().unwrap().unwrap().name, "War of the Spark");
+    /// ```
+    pub fn parent_set(&self) -> Option<crate::Result<Set>> {
+        self.parent_set_code.as_deref().map(Set::code)
+    }
+
+    /// Checks if this set was released before `date`, not counting `date`
+    /// itself. Sets without a release date never were.
+    pub fn released_before(&self, date: NaiveDate) -> bool {
+        self.released_at.is_some_and(|released| released < date)
+    }
+
+    /// Checks if this set was released after `date`, not counting `date`
+    /// itself. Sets without a release date never were.
+    pub fn released_after(&self, date: NaiveDate) -> bool {
+        self.released_at.is_some_and(|released| released > date)
+    }
+
+    /// The URI of this set's icon, an SVG file. This is the same as the
+    /// [`icon_svg_uri`][Set::icon_svg_uri] field.
+    pub fn icon_uri(&self) -> &str {
+        &self.icon_svg_uri
+    }
+
+    /// Downloads this set's icon as SVG. See [`icon_uri`][Set::icon_uri].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::set::Set;
+    /// let icon = Set::code("war").unwrap().fetch_icon().unwrap();
+    /// assert!(String::from_utf8(icon).unwrap().contains("<svg"));
+    /// ```
+    pub fn fetch_icon(&self) -> crate::Result<Vec<u8>> {
+        let mut icon = Vec::new();
+        Uri::<()>::try_from(self.icon_uri())?
+            .fetch_raw()?
+            .into_reader()
+            .read_to_end(&mut icon)?;
+        Ok(icon)
+    }
+}
+
+/// Sorts `sets` newest first, with sets without a release date at the end.
+fn sort_by_release(sets: &mut [Set]) {
+    sets.sort_by_key(|set| std::cmp::Reverse(set.released_at))
+}
+
+/// Keeps the sets in the block with `block_code`, oldest first.
+fn in_block(mut sets: Vec<Set>, block_code: &str) -> Vec<Set> {
+    sets.retain(|set| set.block_code.as_deref() == Some(block_code));
+    sort_by_release(&mut sets);
+    sets.reverse();
+    sets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(code: &str, released_at: Option<&str>) -> Set {
+        let api = "https://api.scryfall.com";
+        serde_json::from_value(serde_json::json!({
+            "id": "2ec77b94-6d47-4891-a480-5d0b4e5c9372",
+            "code": code,
+            "name": code,
+            "set_type": "expansion",
+            "released_at": released_at,
+            "card_count": 1,
+            "digital": false,
+            "foil_only": false,
+            "nonfoil_only": false,
+            "scryfall_uri": format!("https://scryfall.com/sets/{}", code),
+            "uri": format!("{}/sets/{}", api, code),
+            "icon_svg_uri": format!("https://svgs.scryfall.io/sets/{}.svg", code),
+            "search_uri": format!("{}/cards/search?q=e%3A{}", api, code),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn sorted_by_release() {
+        let mut sets = vec![
+            set("lea", Some("1993-08-05")),
+            set("unk", None),
+            set("war", Some("2019-05-03")),
+            set("zen", Some("2009-10-02")),
+        ];
+        sort_by_release(&mut sets);
+        let codes = sets.iter().map(|s| s.code.as_str()).collect::<Vec<_>>();
+        assert_eq!(codes, ["war", "zen", "lea", "unk"]);
+
+        let date = NaiveDate::from_ymd_opt(2009, 10, 2).unwrap();
+        assert!(sets[0].released_after(date));
+        assert!(!sets[1].released_after(date));
+        assert!(!sets[1].released_before(date));
+        assert!(sets[2].released_before(date));
+        assert!(!sets[3].released_before(date) && !sets[3].released_after(date));
+    }
+
+    #[test]
+    fn block_grouping() {
+        let in_ravnica = |mut set: Set| {
+            set.block_code = Some("rav".to_string());
+            set.block = Some("Ravnica".to_string());
+            set
+        };
+        let sets = vec![
+            in_ravnica(set("dis", Some("2006-05-05"))),
+            set("war", Some("2019-05-03")),
+            in_ravnica(set("rav", Some("2005-10-07"))),
+            in_ravnica(set("gpt", Some("2006-02-03"))),
+        ];
+        let block = in_block(sets, "rav");
+        let codes = block.iter().map(|s| s.code.as_str()).collect::<Vec<_>>();
+        assert_eq!(codes, ["rav", "gpt", "dis"]);
+
+        let war = set("war", Some("2019-05-03"));
+        let block = war.block_sets().unwrap();
+        assert_eq!(block.len(), 1);
+        assert_eq!(block[0], war);
+        assert!(war.parent_set().is_none());
+    }
 }
@@ -7,6 +7,8 @@ use serde::ser::Serializer;
 use serde::{Deserialize, Serialize};
 use tinyvec::ArrayVec;
 
+use crate::error::Error;
+
 /// A 3 to 6 letter set code, like 'war' for 'War of the Spark'.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct SetCode(ArrayVec<[u8; 6]>);
@@ -15,19 +17,18 @@ pub struct SetCode(ArrayVec<[u8; 6]>);
 impl SetCode {
     /// Creates a set code from a str.
     ///
-    /// Valid set codes are ascii between 3 and 6 letters long. If any of these
-    /// conditions fails, the conversion fails.
-    ///
-    /// The error value is None if the `str` was not ascii, otherwise it holds
-    /// the size of the `str`.
+    /// Valid set codes are between 3 and 6 ascii letters or digits long. The
+    /// code is converted to lowercase, which is how Scryfall reports set
+    /// codes.
     ///
     /// ```rust
     /// use scryfall::set::SetCode;
     ///
-    /// assert_eq!(SetCode::new("war").unwrap().as_ref(), "war")
+    /// assert_eq!(SetCode::new("WAR").unwrap().as_str(), "war");
+    /// assert!(SetCode::new("war of the spark").is_err());
     /// ```
-    pub fn new(code: &str) -> Result<Self, Option<usize>> {
-        SetCode::try_from(code)
+    pub fn new(code: &str) -> crate::Result<Self> {
+        SetCode::try_from(code).map_err(|_| Error::Other(format!("Invalid set code: {:?}", code)))
     }
 
     /// Returns a reference to the inner set code.
@@ -36,20 +37,29 @@ impl SetCode {
         // only be created from a valid &str.
         str::from_utf8(self.0.as_slice()).unwrap()
     }
+
+    /// Returns a reference to the inner set code. This is the same as
+    /// [`get`](#method.get).
+    pub fn as_str(&self) -> &str {
+        self.get()
+    }
 }
 
 impl TryFrom<&str> for SetCode {
     type Error = Option<usize>;
 
-    /// See [`new`](#method.new) for documentation on why this might return an
-    /// `Err`.
+    /// Creates a set code from a str, following the same rules as
+    /// [`new`](#method.new).
+    ///
+    /// The error value is None if the `str` has characters other than ascii
+    /// letters and digits, otherwise it holds the size of the `str`.
     fn try_from(code: &str) -> Result<Self, Option<usize>> {
-        if !code.is_ascii() {
+        if !code.bytes().all(|b| b.is_ascii_alphanumeric()) {
             return Err(None);
         }
         let code = code.as_bytes();
         Ok(SetCode(match code.len() {
-            3..=6 => code.iter().cloned().collect(),
+            3..=6 => code.iter().map(u8::to_ascii_lowercase).collect(),
             invalid => return Err(Some(invalid)),
         }))
     }
@@ -72,7 +82,7 @@ impl<'de> Visitor<'de> for SetCodeVisitor {
     fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.size {
             Some(size) => write!(f, "set code size between 3 and 6, found {}", size),
-            None => write!(f, "set code to be ascii"),
+            None => write!(f, "set code to be ascii alphanumeric"),
         }
     }
 
@@ -110,3 +120,35 @@ impl fmt::Display for SetCode {
         write!(f, "{}", self.get())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_set_codes() {
+        for (code, expected) in &[
+            ("ktk", "ktk"),
+            ("war", "war"),
+            ("PLIST", "plist"),
+            ("h1r", "h1r"),
+        ] {
+            assert_eq!(SetCode::new(code).unwrap().as_str(), *expected);
+        }
+    }
+
+    #[test]
+    fn invalid_set_codes() {
+        let matrix = vec![
+            ("", Some(0)),
+            ("ab", Some(2)),
+            ("toolong", Some(7)),
+            ("w ar", None),
+            ("wär", None),
+        ];
+        for (code, error) in matrix {
+            assert_eq!(SetCode::try_from(code), Err(error), "{:?}", code);
+            assert!(SetCode::new(code).is_err(), "{:?}", code);
+        }
+    }
+}
@@ -2,12 +2,11 @@
 //! property.
 use std::fmt;
 
-use serde::{Deserialize, Serialize};
+use crate::util::{from_str_by_display, serde_by_display};
 
 /// Scryfall provides an overall categorization for each Set in the set_type
 /// property.
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[non_exhaustive]
 pub enum SetType {
     /// A yearly Magic core set (Tenth Edition, etc)
@@ -16,8 +15,14 @@ pub enum SetType {
     Expansion,
     /// A reprint set that contains no new cards (Modern Masters, etc)
     Masters,
+    /// A set for eternal formats, such as Dominaria Remastered
+    Eternal,
+    /// An Arena set designed for Alchemy
+    Alchemy,
     /// Masterpiece Series premium foil cards
     Masterpiece,
+    /// A Commander-oriented gift set, such as Commander's Arsenal
+    Arsenal,
     /// From the Vault gift sets
     FromTheVault,
     /// Spellbook series gift sets
@@ -43,7 +48,6 @@ pub enum SetType {
     /// A starter/introductory set (Portal, etc)
     Starter,
     /// A gift box set
-    #[serde(rename = "box")]
     GiftBox,
     /// A set that contains purely promotional cards
     Promo,
@@ -52,6 +56,11 @@ pub enum SetType {
     /// A set made up of gold-bordered, oversize, or trophy cards that are not
     /// legal
     Memorabilia,
+    /// A set that contains minigame card inserts from booster packs
+    Minigame,
+    /// A set type that this version of the crate doesn't know about yet, holding
+    /// the value Scryfall sent.
+    Unknown(String),
 }
 
 impl fmt::Display for SetType {
@@ -63,7 +72,10 @@ impl fmt::Display for SetType {
                 SetType::Core => "core",
                 SetType::Expansion => "expansion",
                 SetType::Masters => "masters",
+                SetType::Eternal => "eternal",
+                SetType::Alchemy => "alchemy",
                 SetType::Masterpiece => "masterpiece",
+                SetType::Arsenal => "arsenal",
                 SetType::FromTheVault => "from_the_vault",
                 SetType::Spellbook => "spellbook",
                 SetType::PremiumDeck => "premium_deck",
@@ -76,11 +88,39 @@ impl fmt::Display for SetType {
                 SetType::Vanguard => "vanguard",
                 SetType::Funny => "funny",
                 SetType::Starter => "starter",
-                SetType::GiftBox => "gift_box",
+                SetType::GiftBox => "box",
                 SetType::Promo => "promo",
                 SetType::Token => "token",
                 SetType::Memorabilia => "memorabilia",
+                SetType::Minigame => "minigame",
+                SetType::Unknown(s) => s,
             }
         )
     }
 }
+
+from_str_by_display! {
+    SetType, "set type", [
+        Core, Expansion, Masters, Eternal, Alchemy, Masterpiece, Arsenal, FromTheVault,
+        Spellbook, PremiumDeck, DuelDeck, DraftInnovation, TreasureChest, Commander,
+        Planechase, Archenemy, Vanguard, Funny, Starter, GiftBox, Promo, Token, Memorabilia,
+        Minigame,
+    ]
+}
+
+serde_by_display!(SetType);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_matches_serde() {
+        for set_type in SetType::VARIANTS {
+            assert_eq!(
+                serde_json::to_string(set_type).unwrap(),
+                format!("\"{}\"", set_type)
+            );
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! Card symbols are the mana symbols, tap symbols, and other symbols that can
+//! appear in mana costs and rules text. Scryfall provides a list of all of
+//! them, as well as a way to parse and normalize a mana cost.
+//!
+//! Visit the official [docs](https://scryfall.com/docs/api/card-symbols) for more documentation.
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::card::Color;
+use crate::list::List;
+use crate::uri::Uri;
+use crate::util::SYMBOLOGY_URL;
+
+/// A Card Symbol object represents an illustrated symbol that may appear in
+/// card’s mana cost or Oracle text. Symbols are based on the notation used in
+/// the Comprehensive Rules.
+///
+/// ---
+///
+/// For more information, refer to the [official docs](https://scryfall.com/docs/api/card-symbols).
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct CardSymbol {
+    /// The plaintext symbol. Often surrounded with curly braces `{}`. Note
+    /// that not all symbols are ASCII text (for example, `{∞}`).
+    pub symbol: String,
+
+    /// An alternate version of this symbol, if it is possible to write it
+    /// without curly braces.
+    pub loose_variant: Option<String>,
+
+    /// An English snippet that describes this symbol. Appropriate for use in
+    /// alt text or other accessible communication formats.
+    pub english: String,
+
+    /// True if it is possible to write this symbol “backwards”. For example,
+    /// the official symbol `{U/P}` is sometimes written as `{P/U}` or `{P\U}`
+    /// in informal settings.
+    pub transposable: bool,
+
+    /// True if this is a mana symbol.
+    pub represents_mana: bool,
+
+    /// A decimal number representing this symbol’s converted mana cost. Note
+    /// that mana symbols from funny sets can have fractional converted mana
+    /// costs.
+    pub cmc: Option<f32>,
+
+    /// True if this symbol appears in a mana cost on any Magic card. For
+    /// example `{20}` has this field set to false because `{20}` only appears
+    /// in Oracle text, not mana costs.
+    pub appears_in_mana_costs: bool,
+
+    /// True if this symbol is only used on funny cards or Un-cards.
+    pub funny: bool,
+
+    /// An array of colors representing this symbol.
+    pub colors: Vec<Color>,
+
+    /// An array of plaintext versions of this symbol that Gatherer uses on old
+    /// cards to describe original printed text. For example: `{W}` has
+    /// `["oW", "ooW"]` as alternates.
+    pub gatherer_alternates: Option<Vec<String>>,
+
+    /// A URI to an SVG image of this symbol on Scryfall’s CDNs.
+    pub svg_uri: Option<Url>,
+}
+
+/// A mana cost that was parsed and normalized by Scryfall, as returned by
+/// [`CardSymbol::parse_mana`].
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct ParsedMana {
+    /// The normalized cost, with correctly-ordered and wrapped mana symbols.
+    pub cost: String,
+
+    /// The converted mana cost. If you submit Un-set mana symbols, this
+    /// decimal could include fractional parts.
+    pub cmc: f32,
+
+    /// The colors of the given cost.
+    pub colors: Vec<Color>,
+
+    /// True if the cost is colorless.
+    pub colorless: bool,
+
+    /// True if the cost is monocolored.
+    pub monocolored: bool,
+
+    /// True if the cost is multicolored.
+    pub multicolored: bool,
+}
+
+impl CardSymbol {
+    /// Returns all card symbols known to Scryfall.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::symbology::CardSymbol;
+    /// let symbols = CardSymbol::all().unwrap();
+    /// assert!(symbols.iter().any(|s| s.symbol == "{T}"));
+    /// ```
+    pub fn all() -> crate::Result<Vec<CardSymbol>> {
+        Uri::<List<CardSymbol>>::from(SYMBOLOGY_URL.clone()).fetch_all()
+    }
+
+    /// Parses the mana cost `cost`, normalizing it and computing its converted
+    /// mana cost and colors. Scryfall is lenient about the format and will
+    /// accept costs such as `RUx`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::symbology::CardSymbol;
+    /// let parsed = CardSymbol::parse_mana("RUx").unwrap();
+    /// assert_eq!(parsed.cost, "{X}{U}{R}");
+    /// assert_eq!(parsed.cmc, 2.0);
+    /// assert!(parsed.multicolored);
+    /// ```
+    pub fn parse_mana(cost: &str) -> crate::Result<ParsedMana> {
+        let mut url = SYMBOLOGY_URL.join("parse-mana")?;
+        url.query_pairs_mut().append_pair("cost", cost);
+        Uri::from(url).fetch()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::from_str;
+
+    use super::*;
+
+    #[test]
+    fn parse_card_symbol() {
+        let symbol: CardSymbol = from_str(
+            r#"{
+              "object": "card_symbol",
+              "symbol": "{W/U}",
+              "svg_uri": "https://c2.scryfall.com/file/scryfall-symbols/card-symbols/WU.svg",
+              "loose_variant": null,
+              "english": "one white or blue mana",
+              "transposable": false,
+              "represents_mana": true,
+              "appears_in_mana_costs": true,
+              "cmc": 1.0,
+              "funny": false,
+              "colors": ["W", "U"],
+              "gatherer_alternates": ["(u/w)", "(w/u)"]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(symbol.colors, [Color::White, Color::Blue]);
+        assert_eq!(symbol.cmc, Some(1.0));
+    }
+
+    #[test]
+    fn parse_parsed_mana() {
+        let parsed: ParsedMana = from_str(
+            r#"{
+              "object": "mana_cost",
+              "cost": "{X}{U}{R}",
+              "colors": ["U", "R"],
+              "cmc": 2.0,
+              "colorless": false,
+              "monocolored": false,
+              "multicolored": true
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(parsed.cost, "{X}{U}{R}");
+        assert_eq!(parsed.colors, [Color::Blue, Color::Red]);
+    }
+}
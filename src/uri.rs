@@ -4,18 +4,123 @@
 //! run to obtain more information. This module abstracts the work of fetching
 //! that data.
 use std::convert::TryFrom;
+use std::fmt;
+use std::io::Read;
 use std::marker::PhantomData;
+use std::sync::{Mutex, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
 
+#[cfg(feature = "async")]
+use futures::stream::{self, Stream};
 use httpstatus::StatusCode;
+use once_cell::sync::Lazy;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use ureq::Agent;
+use ureq::Request;
 use url::Url;
 
+use crate::client::ScryfallClient;
 use crate::error::Error;
 use crate::list::{List, ListIter};
 
-thread_local!(static CLIENT: Agent = Agent::new());
+/// The minimum delay between requests used unless
+/// [`set_min_request_delay`] is called.
+pub const DEFAULT_MIN_REQUEST_DELAY: Duration = Duration::from_millis(100);
+
+static RATE_LIMITER: Lazy<Mutex<RateLimiter>> =
+    Lazy::new(|| Mutex::new(RateLimiter::new(DEFAULT_MIN_REQUEST_DELAY)));
+
+/// The `User-Agent` header sent with requests unless [`set_user_agent`] or
+/// [`ScryfallClient::user_agent`] is called.
+pub const DEFAULT_USER_AGENT: &str = concat!("scryfall-rs/", env!("CARGO_PKG_VERSION"));
+
+/// The `Accept` header sent with requests. JSON is preferred, but images are
+/// also downloaded through the same requests.
+const ACCEPT: &str = "application/json;q=0.9,*/*;q=0.8";
+
+static USER_AGENT: Lazy<RwLock<String>> = Lazy::new(|| RwLock::new(DEFAULT_USER_AGENT.to_string()));
+
+/// Sets the `User-Agent` header sent with requests by the functions that don't
+/// take a [`ScryfallClient`], like [`Card::named`], shared by all threads.
+/// Scryfall asks applications to identify themselves with it, and may reject
+/// requests that don't.
+///
+/// The default is [`DEFAULT_USER_AGENT`]. Clients built explicitly aren't
+/// affected by this; use [`ScryfallClient::user_agent`] to set theirs.
+///
+/// [`Card::named`]: crate::card::Card::named
+pub fn set_user_agent(user_agent: &str) {
+    *USER_AGENT.write().unwrap_or_else(|e| e.into_inner()) = user_agent.to_string();
+}
+
+/// The user agent set with [`set_user_agent`].
+pub(crate) fn global_user_agent() -> String {
+    USER_AGENT.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Sets the minimum delay between two requests to the API, shared by all
+/// threads. Scryfall asks for 50-100 milliseconds between requests, and may
+/// respond with `429 Too Many Requests` to clients that send them faster.
+///
+/// The default is [`DEFAULT_MIN_REQUEST_DELAY`]. A delay of zero disables the
+/// rate limiting.
+pub fn set_min_request_delay(delay: Duration) {
+    RATE_LIMITER
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .min_delay = delay;
+}
+
+/// Waits until a request may be sent without exceeding the rate limit.
+fn throttle() {
+    let wait = reserve_request();
+    if wait > Duration::from_secs(0) {
+        thread::sleep(wait);
+    }
+}
+
+/// Like [`throttle`], but doesn't block the thread while waiting.
+#[cfg(feature = "async")]
+async fn throttle_async() {
+    let wait = reserve_request();
+    if wait > Duration::from_secs(0) {
+        tokio::time::sleep(wait).await;
+    }
+}
+
+/// Reserves a slot for a request, returning how long to wait until it.
+fn reserve_request() -> Duration {
+    RATE_LIMITER
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .reserve(Instant::now())
+}
+
+struct RateLimiter {
+    min_delay: Duration,
+    next_request: Option<Instant>,
+}
+
+impl RateLimiter {
+    fn new(min_delay: Duration) -> Self {
+        RateLimiter {
+            min_delay,
+            next_request: None,
+        }
+    }
+
+    /// Reserves the next free slot for a request, returning how long to wait
+    /// from `now` until it.
+    fn reserve(&mut self, now: Instant) -> Duration {
+        let slot = match self.next_request {
+            Some(next) if next > now => next,
+            _ => now,
+        };
+        self.next_request = Some(slot + self.min_delay);
+        slot - now
+    }
+}
 
 /// An unresolved URI returned by the Scryfall API, or generated by this crate.
 ///
@@ -24,6 +129,22 @@ thread_local!(static CLIENT: Agent = Agent::new());
 /// [`List`][crate::list::List]`<_>`, then additional methods `fetch_iter`
 /// and `fetch_all` are available, giving access to objects from all pages
 /// of the collection.
+///
+/// Many fields of [`Card`][crate::Card], such as `set_uri`,
+/// `prints_search_uri` and `rulings_uri`, are `Uri`s that can be followed
+/// directly.
+///
+/// # Example
+/// ```rust
+/// # use scryfall::Card;
+/// # fn main() -> scryfall::Result<()> {
+/// let card = Card::named("Lightning Bolt")?;
+/// let set = card.set_uri.fetch()?;
+/// assert_eq!(set.code, card.set);
+/// println!("{} was printed in {}", card.name, set.name);
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
 #[serde(transparent)]
 pub struct Uri<T> {
@@ -48,6 +169,26 @@ impl<T> From<Url> for Uri<T> {
     }
 }
 
+impl<T> fmt::Display for Uri<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.url, f)
+    }
+}
+
+impl<T> Uri<T> {
+    /// Returns this URI as a string slice.
+    pub fn as_str(&self) -> &str {
+        self.url.as_str()
+    }
+
+    /// Returns this URI as a [`Url`].
+    pub fn as_url(&self) -> &Url {
+        &self.url
+    }
+}
+
+// `ureq::Error` is large, but it is boxed as soon as it leaves this impl.
+#[allow(clippy::result_large_err)]
 impl<T: DeserializeOwned> Uri<T> {
     /// Fetches a resource from the Scryfall API and deserializes it into a type
     /// `T`.
@@ -64,23 +205,171 @@ impl<T: DeserializeOwned> Uri<T> {
     /// assert_eq!(bolt.mana_cost, Some("{R}".to_string()));
     /// ```
     pub fn fetch(&self) -> crate::Result<T> {
-        match self.fetch_raw() {
-            Ok(response) => match response.status() {
-                200..=299 => Ok(serde_json::from_reader(response.into_reader())?),
-                status => Err(Error::HttpError(StatusCode::from(status))),
-            },
-            Err(e) => Err(e),
+        ScryfallClient::with_default(|client| self.fetch_with(client))
+    }
+
+    pub(crate) fn fetch_with(&self, client: &ScryfallClient) -> crate::Result<T> {
+        if !client.has_cache() {
+            return deserialize_response(self.fetch_raw_with(client)?);
+        }
+        let url = client.resolve(&self.url)?;
+        if let Some(body) = client.cached(&url) {
+            return parse_body(url.as_str(), &body);
+        }
+        let response = self.fetch_raw_with(client)?;
+        let status = response.status();
+        if !(200..=299).contains(&status) {
+            return Err(Error::HttpError(StatusCode::from(status)));
         }
+        let response_url = response.get_url().to_string();
+        let body = read_body(response)?;
+        let value = parse_body(&response_url, &body)?;
+        client.store(&url, body.into());
+        Ok(value)
+    }
+
+    /// Like [`fetch_with`][Uri::fetch_with], but always sends a request, for
+    /// resources like random cards that change every time they're fetched.
+    pub(crate) fn fetch_uncached_with(&self, client: &ScryfallClient) -> crate::Result<T> {
+        deserialize_response(self.fetch_raw_with(client)?)
+    }
+
+    /// Sends `body` as JSON to this URI with a POST request, deserializing the
+    /// response into a type `T`.
+    pub(crate) fn post(&self, body: &impl Serialize) -> crate::Result<T> {
+        deserialize_response(self.post_raw(body)?)
     }
 
     pub(crate) fn fetch_raw(&self) -> crate::Result<ureq::Response> {
-        match CLIENT.with(|client| client.request_url("GET", &self.url).call()) {
-            Ok(response) => Ok(response),
-            Err(ureq::Error::Status(400..=599, response)) => Err(Error::ScryfallError(
-                serde_json::from_reader(response.into_reader())?,
-            )),
-            Err(err) => Err(Error::UreqError(err.into(), self.url.to_string())),
+        ScryfallClient::with_default(|client| self.fetch_raw_with(client))
+    }
+
+    fn fetch_raw_with(&self, client: &ScryfallClient) -> crate::Result<ureq::Response> {
+        self.send(client, "GET", Request::call)
+    }
+
+    pub(crate) fn post_raw(&self, body: &impl Serialize) -> crate::Result<ureq::Response> {
+        let body = serde_json::to_string(body)?;
+        ScryfallClient::with_default(|client| {
+            self.send(client, "POST", |request| {
+                request
+                    .set("Content-Type", "application/json")
+                    .send_string(&body)
+            })
+        })
+    }
+
+    fn send(
+        &self,
+        client: &ScryfallClient,
+        method: &str,
+        send: impl Fn(Request) -> Result<ureq::Response, ureq::Error>,
+    ) -> crate::Result<ureq::Response> {
+        let url = client.resolve(&self.url)?;
+        let policy = client.get_retry_policy();
+        let user_agent = client.get_user_agent();
+        let mut attempt = 1;
+        loop {
+            throttle();
+            let mut request = client
+                .agent()
+                .request_url(method, &url)
+                .set("Accept", ACCEPT);
+            if let Some(user_agent) = &user_agent {
+                request = request.set("User-Agent", user_agent);
+            }
+            match send(request) {
+                Ok(response) => return Ok(response),
+                Err(ureq::Error::Status(status, response))
+                    if policy.should_retry(status, attempt) =>
+                {
+                    thread::sleep(policy.delay(attempt, response.header("Retry-After")));
+                    attempt += 1;
+                },
+                Err(ureq::Error::Status(400..=599, response)) => {
+                    return Err(Error::ScryfallError(parse_json(response)?))
+                },
+                Err(err) => return Err(Error::UreqError(err.into(), url.to_string())),
+            }
+        }
+    }
+}
+
+fn deserialize_response<T: DeserializeOwned>(response: ureq::Response) -> crate::Result<T> {
+    match response.status() {
+        200..=299 => parse_json(response),
+        status => Err(Error::HttpError(StatusCode::from(status))),
+    }
+}
+
+/// How much of a response body to keep in a
+/// [`ResponseJsonError`][Error::ResponseJsonError].
+const ERROR_BODY_PREFIX_LEN: usize = 512;
+
+/// Deserializes the body of `response`. If that fails, the error includes the
+/// URL of the request and the start of the body.
+fn parse_json<T: DeserializeOwned>(response: ureq::Response) -> crate::Result<T> {
+    let url = response.get_url().to_string();
+    parse_body(&url, &read_body(response)?)
+}
+
+fn read_body(response: ureq::Response) -> crate::Result<Vec<u8>> {
+    let mut body = Vec::new();
+    response.into_reader().read_to_end(&mut body)?;
+    Ok(body)
+}
+
+/// Deserializes the `body` of a response to a request to `url`.
+fn parse_body<T: DeserializeOwned>(url: &str, body: &[u8]) -> crate::Result<T> {
+    serde_json::from_slice(body).map_err(|error| {
+        let prefix = &body[..body.len().min(ERROR_BODY_PREFIX_LEN)];
+        Error::ResponseJsonError {
+            error,
+            url: url.to_string(),
+            body: String::from_utf8_lossy(prefix).into_owned(),
         }
+    })
+}
+
+impl<T> Uri<List<T>> {
+    /// Returns the URI of page `n` of this list, starting at page 1. Scryfall
+    /// returns an error for pages past the end of the list.
+    ///
+    /// The number of items per page is set by Scryfall, and can't be changed.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::convert::TryFrom;
+    /// #
+    /// # use scryfall::Card;
+    /// # use scryfall::list::List;
+    /// # use scryfall::uri::Uri;
+    /// let uri = Uri::<List<Card>>::try_from("https://api.scryfall.com/cards/search?q=t:goblin").unwrap();
+    /// let page_3 = uri.page(3);
+    /// assert_eq!(page_3.current_page(), 3);
+    /// assert!(page_3.fetch().unwrap().data.len() > 0);
+    /// ```
+    pub fn page(&self, n: usize) -> Self {
+        let mut url = self.url.clone();
+        let pairs = self
+            .url
+            .query_pairs()
+            .filter(|(key, _)| key != "page")
+            .collect::<Vec<_>>();
+        url.query_pairs_mut()
+            .clear()
+            .extend_pairs(pairs)
+            .append_pair("page", &n.max(1).to_string());
+        Uri::from(url)
+    }
+
+    /// The page of the list this URI points to, starting at page 1.
+    pub fn current_page(&self) -> usize {
+        self.url
+            .query_pairs()
+            .find(|(key, _)| key == "page")
+            .and_then(|(_, page)| page.parse().ok())
+            .unwrap_or(1)
     }
 }
 
@@ -108,6 +397,10 @@ impl<T: DeserializeOwned> Uri<List<T>> {
         Ok(self.fetch()?.into_iter())
     }
 
+    pub(crate) fn fetch_iter_with(&self, client: &ScryfallClient) -> crate::Result<ListIter<T>> {
+        Ok(self.fetch_with(client)?.into_iter().with_client(client))
+    }
+
     /// Eagerly fetch items from all pages of a list. If any of the pages fail
     /// to load, returns an error.
     ///
@@ -124,15 +417,195 @@ impl<T: DeserializeOwned> Uri<List<T>> {
     /// assert_eq!(uri.fetch_all().unwrap().len(), 76);
     /// ```
     pub fn fetch_all(&self) -> crate::Result<Vec<T>> {
+        ScryfallClient::with_default(|client| self.fetch_all_with(client))
+    }
+
+    pub(crate) fn fetch_all_with(&self, client: &ScryfallClient) -> crate::Result<Vec<T>> {
         let mut items = vec![];
-        let mut next_page = Some(self.fetch()?);
+        let mut next_page = Some(self.fetch_with(client)?);
         while let Some(page) = next_page {
-            items.extend(page.data.into_iter());
+            items.extend(page.data);
             next_page = match page.next_page {
-                Some(uri) => Some(uri.fetch()?),
+                Some(uri) => Some(uri.fetch_with(client)?),
                 None => None,
             };
         }
         Ok(items)
     }
 }
+
+/// The HTTP client used by the async functions, like
+/// [`Card::named_async`][crate::Card::named_async].
+#[cfg(feature = "async")]
+static ASYNC_CLIENT: Lazy<reqwest::Client> = Lazy::new(reqwest::Client::new);
+
+#[cfg(feature = "async")]
+impl<T: DeserializeOwned> Uri<T> {
+    /// Like [`fetch`][Uri::fetch], but asynchronous. Requires the `async`
+    /// feature, and must be run by a [Tokio](https://tokio.rs) runtime.
+    ///
+    /// Requests are sent with the user agent, retry policy and rate limit of
+    /// the blocking functions that don't take a [`ScryfallClient`].
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use std::convert::TryFrom;
+    /// #
+    /// # use scryfall::card::Card;
+    /// # use scryfall::uri::Uri;
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() -> scryfall::Result<()> {
+    /// let uri = Uri::<Card>::try_from("https://api.scryfall.com/cards/named?exact=Lightning+Bolt")?;
+    /// let bolt = uri.fetch_async().await?;
+    /// assert_eq!(bolt.mana_cost, Some("{R}".to_string()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_async(&self) -> crate::Result<T> {
+        let response = self.send_async().await?;
+        let url = response.url().to_string();
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| Error::ReqwestError(Box::new(e), url.clone()))?;
+        parse_body(&url, &body)
+    }
+
+    async fn send_async(&self) -> crate::Result<reqwest::Response> {
+        let (url, policy, user_agent) = ScryfallClient::with_default(|client| {
+            let url = client.resolve(&self.url)?;
+            Ok::<_, Error>((url, *client.get_retry_policy(), client.get_user_agent()))
+        })?;
+        let mut attempt = 1;
+        loop {
+            throttle_async().await;
+            let mut request = ASYNC_CLIENT.get(url.clone()).header("Accept", ACCEPT);
+            if let Some(user_agent) = &user_agent {
+                request = request.header("User-Agent", user_agent);
+            }
+            let response = request
+                .send()
+                .await
+                .map_err(|e| Error::ReqwestError(Box::new(e), url.to_string()))?;
+            let status = response.status().as_u16();
+            match status {
+                200..=299 => return Ok(response),
+                _ if policy.should_retry(status, attempt) => {
+                    let retry_after = response
+                        .headers()
+                        .get("Retry-After")
+                        .and_then(|value| value.to_str().ok());
+                    tokio::time::sleep(policy.delay(attempt, retry_after)).await;
+                    attempt += 1;
+                },
+                400..=599 => {
+                    let body = response
+                        .bytes()
+                        .await
+                        .map_err(|e| Error::ReqwestError(Box::new(e), url.to_string()))?;
+                    return Err(Error::ScryfallError(parse_body(url.as_str(), &body)?));
+                },
+                status => return Err(Error::HttpError(StatusCode::from(status))),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: DeserializeOwned> Uri<List<T>> {
+    /// Lazily fetches all pages of a list, as a [`Stream`]. Following pages are
+    /// requested once the previous page has been taken from the stream. The
+    /// stream ends after the first error.
+    ///
+    /// Requires the `async` feature. See [`fetch_async`][Uri::fetch_async].
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use std::convert::TryFrom;
+    /// #
+    /// # use futures::TryStreamExt;
+    /// # use scryfall::Card;
+    /// # use scryfall::list::List;
+    /// # use scryfall::uri::Uri;
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() -> scryfall::Result<()> {
+    /// let uri = Uri::<List<Card>>::try_from("https://api.scryfall.com/cards/search?q=t:goblin")?;
+    /// let pages: Vec<List<Card>> = uri.fetch_pages_async().try_collect().await?;
+    /// assert!(pages.len() > 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fetch_pages_async(&self) -> impl Stream<Item = crate::Result<List<T>>> {
+        page_stream(NextPage::Fetch(Uri::from(self.url.clone())))
+    }
+}
+
+/// The state of a [`page_stream`].
+#[cfg(feature = "async")]
+pub(crate) enum NextPage<T> {
+    /// A page that still has to be fetched.
+    Fetch(Uri<List<T>>),
+    /// A page that was already fetched.
+    Ready(List<T>),
+    /// There are no more pages.
+    Done,
+}
+
+/// Streams the pages of a list, starting at `next`.
+#[cfg(feature = "async")]
+pub(crate) fn page_stream<T: DeserializeOwned>(
+    next: NextPage<T>,
+) -> impl Stream<Item = crate::Result<List<T>>> {
+    stream::unfold(next, |next| async move {
+        let page = match next {
+            NextPage::Fetch(uri) => match uri.fetch_async().await {
+                Ok(page) => page,
+                Err(e) => return Some((Err(e), NextPage::Done)),
+            },
+            NextPage::Ready(page) => page,
+            NextPage::Done => return None,
+        };
+        let next = match &page.next_page {
+            Some(uri) if page.has_more => NextPage::Fetch(Uri::from(uri.url.clone())),
+            _ => NextPage::Done,
+        };
+        Some((Ok(page), next))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_limiter_spaces_requests() {
+        let delay = Duration::from_millis(100);
+        let mut limiter = RateLimiter::new(delay);
+        let start = Instant::now();
+
+        assert_eq!(limiter.reserve(start), Duration::from_secs(0));
+        assert_eq!(limiter.reserve(start), delay);
+        assert_eq!(limiter.reserve(start + delay), delay);
+        assert_eq!(limiter.reserve(start + delay * 10), Duration::from_secs(0));
+
+        limiter.min_delay = Duration::from_secs(0);
+        limiter.reserve(start + delay * 20);
+        assert_eq!(limiter.reserve(start + delay * 20), Duration::from_secs(0));
+    }
+
+    #[test]
+    fn list_pages() {
+        let uri =
+            Uri::<List<()>>::try_from("https://api.scryfall.com/cards/search?q=t%3Aelf").unwrap();
+        assert_eq!(uri.current_page(), 1);
+
+        let page_4 = uri.page(4);
+        assert_eq!(page_4.current_page(), 4);
+        assert_eq!(
+            page_4.as_str(),
+            "https://api.scryfall.com/cards/search?q=t%3Aelf&page=4"
+        );
+        assert_eq!(page_4.page(2).as_str(), uri.page(2).as_str());
+        assert_eq!(uri.page(0).current_page(), 1);
+    }
+}
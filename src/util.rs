@@ -4,6 +4,9 @@ use serde::{Deserialize, Deserializer};
 use url::Url;
 
 pub(crate) mod array_stream_reader;
+mod uuid;
+
+pub use self::uuid::Uuid;
 
 /// The [scryfall](https://scryfall.com/docs/api) endpoint.
 pub static ROOT_URL: Lazy<Url> = Lazy::new(|| Url::parse("https://api.scryfall.com/").unwrap());
@@ -15,11 +18,69 @@ pub static SETS_URL: Lazy<Url> = Lazy::new(|| ROOT_URL.join("sets/").unwrap());
 pub static BULK_DATA_URL: Lazy<Url> = Lazy::new(|| ROOT_URL.join("bulk-data/").unwrap());
 /// The [catalog](https://scryfall.com/docs/api/catalogs) endpoint.
 pub static CATALOG_URL: Lazy<Url> = Lazy::new(|| ROOT_URL.join("catalog/").unwrap());
+/// The [symbology](https://scryfall.com/docs/api/card-symbols) endpoint.
+pub static SYMBOLOGY_URL: Lazy<Url> = Lazy::new(|| ROOT_URL.join("symbology/").unwrap());
+/// The [migrations](https://scryfall.com/docs/api/migrations) endpoint.
+pub static MIGRATIONS_URL: Lazy<Url> = Lazy::new(|| ROOT_URL.join("migrations/").unwrap());
 
 /// The [rulings](https://scryfall.com/docs/api/rulings) path segment, which goes on the end of a
 /// card URL.
 pub const API_RULING: &str = "rulings/";
 
+/// Implements `FromStr` for an enum by comparing the input, ignoring case,
+/// with the `Display` output of each of the listed variants. This also defines
+/// a `VARIANTS` constant holding those variants.
+macro_rules! from_str_by_display {
+    ($Ty:ident, $what:literal, [$($variant:ident),* $(,)?]) => {
+        impl $Ty {
+            #[allow(dead_code)]
+            pub(crate) const VARIANTS: &'static [$Ty] = &[$($Ty::$variant),*];
+        }
+
+        impl std::str::FromStr for $Ty {
+            type Err = crate::error::Error;
+
+            fn from_str(s: &str) -> crate::Result<Self> {
+                $Ty::VARIANTS
+                    .iter()
+                    .find(|variant| variant.to_string().eq_ignore_ascii_case(s))
+                    .cloned()
+                    .ok_or_else(|| {
+                        crate::error::Error::Other(format!(concat!("Unknown ", $what, ": {:?}"), s))
+                    })
+            }
+        }
+    };
+}
+pub(crate) use from_str_by_display;
+
+/// Implements `Serialize` and `Deserialize` for an enum using the `Display`
+/// output of each variant in its `VARIANTS` constant, as defined by
+/// [`from_str_by_display`]. Unlike `FromStr`, deserializing never fails on an
+/// unknown value: it becomes the enum's `Unknown` variant, holding the
+/// original string, so new values from Scryfall don't break parsing.
+macro_rules! serde_by_display {
+    ($Ty:ident) => {
+        impl serde::Serialize for $Ty {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $Ty {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+                Ok($Ty::VARIANTS
+                    .iter()
+                    .find(|variant| variant.to_string() == s)
+                    .cloned()
+                    .unwrap_or($Ty::Unknown(s)))
+            }
+        }
+    };
+}
+pub(crate) use serde_by_display;
+
 /// Function for use with `#[serde(deserialize_with)]` and a field that's
 /// Option<T>. If deserialization fails, use `None` as the field's value and
 /// don't cause an error.
@@ -0,0 +1,107 @@
+//! This module defines the id type used by Scryfall objects.
+use std::{fmt, str};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// A Scryfall id, like a card's [`id`][crate::Card::id] or
+/// [`oracle_id`][crate::Card::oracle_id].
+///
+/// It can only hold a valid UUID, so ids parsed from strings are checked
+/// before they are sent to Scryfall. It serializes and displays in the
+/// hyphenated lowercase form Scryfall uses.
+///
+/// ```rust
+/// use scryfall::Uuid;
+///
+/// let id: Uuid = "0b81b329-4ef5-4b55-9fe7-9ed69477e96b".parse().unwrap();
+/// assert_eq!(id.to_string(), "0b81b329-4ef5-4b55-9fe7-9ed69477e96b");
+/// assert!("not an id".parse::<Uuid>().is_err());
+/// ```
+#[derive(
+    Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Uuid(::uuid::Uuid);
+
+impl Uuid {
+    /// Creates an id from a str, failing if it isn't a valid UUID.
+    ///
+    /// ```rust
+    /// use scryfall::Uuid;
+    ///
+    /// assert!(Uuid::new("0B81B329-4EF5-4B55-9FE7-9ED69477E96B").is_ok());
+    /// assert!(Uuid::new("0b81b329").is_err());
+    /// ```
+    pub fn new(id: &str) -> crate::Result<Self> {
+        ::uuid::Uuid::parse_str(id)
+            .map(Uuid)
+            .map_err(|e| Error::Other(format!("Invalid id {:?}: {}", id, e)))
+    }
+
+    /// The id made of all zeros, which Scryfall never assigns to an object.
+    pub const fn nil() -> Self {
+        Uuid(::uuid::Uuid::nil())
+    }
+
+    /// Returns the inner [`uuid::Uuid`].
+    pub fn get(&self) -> ::uuid::Uuid {
+        self.0
+    }
+}
+
+impl From<::uuid::Uuid> for Uuid {
+    fn from(id: ::uuid::Uuid) -> Self {
+        Uuid(id)
+    }
+}
+
+impl From<Uuid> for ::uuid::Uuid {
+    fn from(id: Uuid) -> Self {
+        id.0
+    }
+}
+
+impl str::FromStr for Uuid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        Uuid::new(s)
+    }
+}
+
+impl fmt::Display for Uuid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_str, to_string};
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let text = "0b81b329-4ef5-4b55-9fe7-9ed69477e96b";
+        let id: Uuid = text.parse().unwrap();
+        assert_eq!(id.to_string(), text);
+        assert_eq!(Uuid::new(&text.to_uppercase()).unwrap(), id);
+
+        let json = to_string(&id).unwrap();
+        assert_eq!(json, format!("{:?}", text));
+        assert_eq!(from_str::<Uuid>(&json).unwrap(), id);
+
+        let inner: ::uuid::Uuid = id.into();
+        assert_eq!(Uuid::from(inner), id);
+    }
+
+    #[test]
+    fn rejects_invalid() {
+        assert!(Uuid::new("").is_err());
+        assert!(Uuid::new("0b81b329-4ef5-4b55-9fe7").is_err());
+        assert!(from_str::<Uuid>("\"not an id\"").is_err());
+    }
+}